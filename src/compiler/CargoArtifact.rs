@@ -0,0 +1,84 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A `compiler-artifact` message, for a crate target that cargo built (or found already built)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CargoArtifact
+{
+	/// eg `libc 0.2.24 (registry+https://github.com/rust-lang/crates.io-index)`
+	pub packageIdentifier: String,
+	
+	/// eg `lib`, `proc-macro`, `bin` or `custom-build`
+	pub kinds: Vec<String>,
+	
+	/// As written in Cargo.toml, so may contain hyphens
+	pub targetName: String,
+	
+	pub sourceFilePath: String,
+	
+	/// Features enabled for the package
+	pub features: Vec<String>,
+	
+	/// eg `target/release/deps/liblibc-<hash>.rlib`
+	pub fileNames: Vec<String>,
+}
+
+impl CargoArtifact
+{
+	/// The name `extern crate` uses
+	#[inline(always)]
+	pub fn crateName(&self) -> String
+	{
+		self.targetName.replace('-', "_")
+	}
+	
+	/// The package's name and version, from `packageIdentifier`
+	#[inline(always)]
+	pub fn packageNameAndVersion(&self) -> (&str, &str)
+	{
+		let mut parts = self.packageIdentifier.split(' ');
+		let name = parts.next().unwrap_or("");
+		let version = parts.next().unwrap_or("");
+		(name, version)
+	}
+	
+	/// The file another crate can be linked against with `--extern`, ie an rlib or, for a procedural macro, a dynamic library; `None` for binaries and build scripts
+	pub fn externFilePath(&self) -> Option<&str>
+	{
+		let isLibrary = self.kinds.iter().any(|kind| kind == "lib" || kind == "rlib" || kind == "dylib" || kind == "proc-macro");
+		if !isLibrary
+		{
+			return None;
+		}
+		
+		let withExtension = |extension: &str| self.fileNames.iter().find(|fileName| fileName.ends_with(extension)).map(|fileName| fileName.as_str());
+		withExtension(".rlib").or_else(|| withExtension(".so")).or_else(|| withExtension(".dylib"))
+	}
+	
+	fn fromJson(message: &JsonValue) -> Option<Self>
+	{
+		let target = match message.get("target")
+		{
+			None => return None,
+			Some(target) => target,
+		};
+		
+		match (message.get("package_id").and_then(JsonValue::as_str), target.get("name").and_then(JsonValue::as_str), target.get("src_path").and_then(JsonValue::as_str))
+		{
+			(Some(packageIdentifier), Some(targetName), Some(sourceFilePath)) => Some
+			(
+				Self
+				{
+					packageIdentifier: packageIdentifier.to_owned(),
+					kinds: CargoMessage::jsonStrings(target, "kind"),
+					targetName: targetName.to_owned(),
+					sourceFilePath: sourceFilePath.to_owned(),
+					features: CargoMessage::jsonStrings(message, "features"),
+					fileNames: CargoMessage::jsonStrings(message, "filenames"),
+				}
+			),
+			_ => None,
+		}
+	}
+}
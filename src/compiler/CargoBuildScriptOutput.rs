@@ -0,0 +1,29 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A `build-script-executed` message, with what a package's build script told cargo to pass to rustc
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CargoBuildScriptOutput
+{
+	pub packageIdentifier: String,
+	
+	/// Passed to rustc with `-L`
+	pub linkedPaths: Vec<String>,
+	
+	/// Passed to rustc with `--cfg`
+	pub cfgs: Vec<String>,
+}
+
+impl CargoBuildScriptOutput
+{
+	fn fromJson(message: &JsonValue) -> Option<Self>
+	{
+		message.get("package_id").and_then(JsonValue::as_str).map(|packageIdentifier| Self
+		{
+			packageIdentifier: packageIdentifier.to_owned(),
+			linkedPaths: CargoMessage::jsonStrings(message, "linked_paths"),
+			cfgs: CargoMessage::jsonStrings(message, "cfgs"),
+		})
+	}
+}
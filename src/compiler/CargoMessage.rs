@@ -0,0 +1,65 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A message from cargo's `--message-format=json` output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CargoMessage
+{
+	/// One of rustc's diagnostics, for the crate target whose source file is `sourceFilePath`
+	CompilerMessage { packageIdentifier: String, sourceFilePath: String, diagnostic: RustcDiagnostic },
+	
+	CompilerArtifact(CargoArtifact),
+	
+	BuildScriptExecuted(CargoBuildScriptOutput),
+	
+	/// A message this crate does not use
+	Other,
+}
+
+impl CargoMessage
+{
+	/// Returns the messages and any other output, eg from a build script, as text
+	pub(crate) fn parseCargoOutput(output: &[u8]) -> (Vec<Self>, String)
+	{
+		RustcDiagnostic::parseJsonLines(output, Self::fromJson)
+	}
+	
+	/// Diagnostics of the messages, in order
+	pub(crate) fn diagnostics(messages: &[Self]) -> Vec<RustcDiagnostic>
+	{
+		messages.iter().filter_map(|message| match *message
+		{
+			CargoMessage::CompilerMessage { ref diagnostic, .. } => Some(diagnostic.clone()),
+			_ => None,
+		}).collect()
+	}
+	
+	fn fromJson(message: &JsonValue) -> Option<Self>
+	{
+		let packageIdentifier = || message.get("package_id").and_then(JsonValue::as_str).map(str::to_owned);
+		
+		match message.get("reason").and_then(JsonValue::as_str)
+		{
+			None => None,
+			
+			Some("compiler-message") => match (packageIdentifier(), message.get("target").and_then(|target| target.get("src_path")).and_then(JsonValue::as_str), message.get("message").and_then(RustcDiagnostic::fromJson))
+			{
+				(Some(packageIdentifier), Some(sourceFilePath), Some(diagnostic)) => Some(CargoMessage::CompilerMessage { packageIdentifier: packageIdentifier, sourceFilePath: sourceFilePath.to_owned(), diagnostic: diagnostic }),
+				_ => None,
+			},
+			
+			Some("compiler-artifact") => CargoArtifact::fromJson(message).map(CargoMessage::CompilerArtifact),
+			
+			Some("build-script-executed") => CargoBuildScriptOutput::fromJson(message).map(CargoMessage::BuildScriptExecuted),
+			
+			Some(_) => Some(CargoMessage::Other),
+		}
+	}
+	
+	#[inline(always)]
+	fn jsonStrings(value: &JsonValue, key: &str) -> Vec<String>
+	{
+		RustcDiagnostic::jsonArray(value, key).iter().filter_map(JsonValue::as_str).map(str::to_owned).collect()
+	}
+}
@@ -0,0 +1,295 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Builds a plugin crate, which may have dependencies and several modules, using `cargo build` and then rustc
+/// The crate's dependencies are built with the profile in its `Cargo.toml`; the crate itself is always built as a `staticlib` with `lto`, `codegen-units=1` and `panic=abort`, so that its bit code has no undefined references to its dependencies
+pub struct CargoPluginCompiler
+{
+	pub namedTemporaryFilePathGenerator: NamedTemporaryFilePathGenerator,
+	targetFolderPath: PathBuf,
+	offline: bool,
+	release: bool,
+	binaryName: Option<String>,
+	targetTriple: Option<String>,
+}
+
+impl CargoPluginCompiler
+{
+	pub const TargetFolderName: &'static str = "predicator-cargo-target";
+	
+	/// Builds in a target folder shared by all crates, so dependencies are only compiled once; unlike the scratch folder bit code files are copied to, it is not removed when this is dropped
+	pub fn new(temporaryFolderPath: TemporaryFolderPath) -> Result<Self, CompilerError>
+	{
+		let targetFolderPath = temporaryFolderPath.path().map_err(CompilerError::ScratchFolder)?.join(Self::TargetFolderName);
+		
+		Ok
+		(
+			Self
+			{
+				namedTemporaryFilePathGenerator: NamedTemporaryFilePathGenerator::new(temporaryFolderPath, UsefulRandomFileNameGenerator::newForRustBitCode()).map_err(CompilerError::ScratchFolder)?,
+				targetFolderPath: targetFolderPath,
+				offline: false,
+				release: true,
+				binaryName: None,
+				targetTriple: None,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	pub fn targetFolderPath(mut self, targetFolderPath: PathBuf) -> Self
+	{
+		self.targetFolderPath = targetFolderPath;
+		self
+	}
+	
+	/// Passes `--frozen`, so `Cargo.lock` must be up-to-date and dependencies must already be available, eg vendored using `cargo vendor` with a `[source]` replacement in the crate's `.cargo/config`
+	#[inline(always)]
+	pub fn offline(mut self, offline: bool) -> Self
+	{
+		self.offline = offline;
+		self
+	}
+	
+	/// Defaults to true; uses the crate's `[profile.release]` rather than `[profile.dev]`
+	#[inline(always)]
+	pub fn release(mut self, release: bool) -> Self
+	{
+		self.release = release;
+		self
+	}
+	
+	/// By default the crate's library target (`--lib`) is built
+	#[inline(always)]
+	pub fn binary(mut self, binaryName: &str) -> Self
+	{
+		self.binaryName = Some(binaryName.to_owned());
+		self
+	}
+	
+	/// eg `x86_64-unknown-linux-musl`; by default the host's
+	#[inline(always)]
+	pub fn targetTriple(mut self, targetTriple: &str) -> Self
+	{
+		self.targetTriple = Some(targetTriple.to_owned());
+		self
+	}
+	
+	pub fn compileToBuffer(&mut self, crateName: &str, crateFolderPath: &Path) -> Result<CompilerOutput<Vec<u8>>, CompilerError>
+	{
+		self.compile(crateName, crateFolderPath)?.andThen(|bitCodeFile| bitCodeFile.read())
+	}
+	
+	/// Plugins must have a manifest to be loaded with `JitContext::loadPlugins()`
+	pub fn compileWithManifest(&mut self, crateName: &str, crateFolderPath: &Path, manifest: &PluginManifest, context: &Context) -> Result<CompilerOutput<TemporaryFile>, CompilerError>
+	{
+		self.compile(crateName, crateFolderPath)?.andThen(|bitCodeFile|
+		{
+			manifest.injectIntoBitCodeFile(context, bitCodeFile.filePath()).map_err(CompilerError::Manifest)?;
+			Ok(bitCodeFile)
+		})
+	}
+	
+	/// `crateFolderPath` is the folder containing the crate's `Cargo.toml`; cargo is run in it so that its `.cargo/config` is used
+	/// cargo builds the crate's dependencies, then rustc is run on the crate itself as a `staticlib` with `-C lto`, so that the bit code includes the code it uses from its dependencies (and `core`); the dependencies are those in cargo's `compiler-artifact` messages
+	/// The bit code is written to a file in the scratch folder, so it can be modified (eg by `compileWithManifest()`) without affecting later builds
+	/// Diagnostics are those of every crate rustc was run on, including dependencies
+	pub fn compile(&mut self, crateName: &str, crateFolderPath: &Path) -> Result<CompilerOutput<TemporaryFile>, CompilerError>
+	{
+		let messages = self.build(crateFolderPath)?;
+		
+		let artifacts: Vec<&CargoArtifact> = messages.iter().filter_map(|message| match *message
+		{
+			CargoMessage::CompilerArtifact(ref artifact) => Some(artifact),
+			_ => None,
+		}).collect();
+		
+		// cargo reports the target it was asked to build last, as everything else it built is a dependency of it
+		let (plugin, dependencies) = match artifacts.split_last()
+		{
+			None => return Err(CompilerError::NoBitCodeEmitted { crateName: crateName.to_owned(), folderPath: crateFolderPath.display().to_string() }),
+			Some((plugin, dependencies)) => (*plugin, dependencies),
+		};
+		
+		// The crate's own diagnostics are those of the rustc run below, so that they are not reported twice
+		let mut diagnostics: Vec<RustcDiagnostic> = messages.iter().filter_map(|message| match *message
+		{
+			CargoMessage::CompilerMessage { ref packageIdentifier, ref sourceFilePath, ref diagnostic } => if *packageIdentifier == plugin.packageIdentifier && *sourceFilePath == plugin.sourceFilePath
+			{
+				None
+			}
+			else
+			{
+				Some(diagnostic.clone())
+			},
+			_ => None,
+		}).collect();
+		
+		let bitCodeFile = self.namedTemporaryFilePathGenerator.temporaryBitCodeFile(crateName);
+		
+		let output = match self.rustcCommand(crateFolderPath, plugin, dependencies, &messages, bitCodeFile.filePath()).output()
+		{
+			Err(error) => return Err(CompilerError::CouldNotRun { program: "rustc", error: error }),
+			Ok(output) => output,
+		};
+		
+		let (rustcDiagnostics, mut otherOutput) = RustcDiagnostic::parseRustcOutput(&output.stderr);
+		otherOutput.push_str(&String::from_utf8_lossy(&output.stdout));
+		diagnostics.extend(rustcDiagnostics);
+		
+		if unlikely(!output.status.success() || diagnostics.iter().any(RustcDiagnostic::isError))
+		{
+			return Err(CompilerError::Failed { program: "rustc", status: output.status, diagnostics: diagnostics, otherOutput: otherOutput });
+		}
+		
+		Ok(CompilerOutput::new(bitCodeFile, diagnostics))
+	}
+	
+	/// Runs `cargo build` with the crate's own profile, so that its dependencies are built (or found already built) and the crate is checked
+	fn build(&self, crateFolderPath: &Path) -> Result<Vec<CargoMessage>, CompilerError>
+	{
+		let mut command = match var_os("CARGO")
+		{
+			None => Command::new("cargo"),
+			Some(path) => Command::new(path),
+		};
+		
+		command
+		.current_dir(crateFolderPath)
+		.arg("build")
+		.arg("--manifest-path").arg(crateFolderPath.join("Cargo.toml"))
+		.arg("--target-dir").arg(&self.targetFolderPath)
+		.arg("--message-format").arg("json");
+		
+		if self.release
+		{
+			command.arg("--release");
+		}
+		
+		if self.offline
+		{
+			command.arg("--frozen");
+		}
+		
+		if let Some(ref targetTriple) = self.targetTriple
+		{
+			command.arg("--target").arg(targetTriple);
+		}
+		
+		match self.binaryName
+		{
+			None => command.arg("--lib"),
+			Some(ref binaryName) => command.arg("--bin").arg(binaryName),
+		};
+		
+		let output = match command.output()
+		{
+			Err(error) => return Err(CompilerError::CouldNotRun { program: "cargo", error: error }),
+			Ok(output) => output,
+		};
+		
+		// cargo writes its own messages, eg about fetching or failing to resolve dependencies, to standard error
+		let (messages, mut otherOutput) = CargoMessage::parseCargoOutput(&output.stdout);
+		otherOutput.push_str(&String::from_utf8_lossy(&output.stderr));
+		
+		let diagnostics = CargoMessage::diagnostics(&messages);
+		if unlikely(!output.status.success() || diagnostics.iter().any(RustcDiagnostic::isError))
+		{
+			return Err(CompilerError::Failed { program: "cargo", status: output.status, diagnostics: diagnostics, otherOutput: otherOutput });
+		}
+		
+		Ok(messages)
+	}
+	
+	/// Mirrors what cargo passes to rustc for `plugin`, other than the crate type, output and code generation options; the environment is limited to `CARGO_MANIFEST_DIR`, `CARGO_PKG_NAME` and `CARGO_PKG_VERSION`
+	fn rustcCommand(&self, crateFolderPath: &Path, plugin: &CargoArtifact, dependencies: &[&CargoArtifact], messages: &[CargoMessage], bitCodeFilePath: &Path) -> Command
+	{
+		let mut command = match var_os("RUSTC")
+		{
+			None => Command::new("rustc"),
+			Some(path) => Command::new(path),
+		};
+		
+		let (packageName, packageVersion) = plugin.packageNameAndVersion();
+		
+		command
+		.current_dir(crateFolderPath)
+		.env("CARGO_MANIFEST_DIR", crateFolderPath)
+		.env("CARGO_PKG_NAME", packageName)
+		.env("CARGO_PKG_VERSION", packageVersion)
+		.arg("--crate-name").arg(plugin.crateName())
+		.arg("--error-format").arg("json")
+		.arg(&plugin.sourceFilePath)
+		.arg("--crate-type").arg("staticlib")
+		.arg("--emit").arg("llvm-bc")
+		.arg("-C").arg("lto")
+		.arg("-C").arg("codegen-units=1")
+		.arg("-C").arg("panic=abort")
+		.arg("-o").arg(bitCodeFilePath);
+		
+		if self.release
+		{
+			command.arg("-C").arg("opt-level=3");
+		}
+		else
+		{
+			command.arg("-C").arg("debuginfo=2");
+		}
+		
+		if let Some(ref targetTriple) = self.targetTriple
+		{
+			command.arg("--target").arg(targetTriple);
+		}
+		
+		for feature in plugin.features.iter()
+		{
+			command.arg("--cfg").arg(format!("feature=\"{}\"", feature));
+		}
+		
+		for message in messages.iter()
+		{
+			if let CargoMessage::BuildScriptExecuted(ref buildScriptOutput) = *message
+			{
+				if buildScriptOutput.packageIdentifier != plugin.packageIdentifier
+				{
+					continue;
+				}
+				
+				for cfg in buildScriptOutput.cfgs.iter()
+				{
+					command.arg("--cfg").arg(cfg);
+				}
+				
+				for linkedPath in buildScriptOutput.linkedPaths.iter()
+				{
+					command.arg("-L").arg(linkedPath);
+				}
+			}
+		}
+		
+		// Every library cargo built is passed, as its messages do not say which are direct dependencies; transitive dependencies are found in their folders
+		let mut dependencyFolderPaths: Vec<&Path> = Vec::new();
+		for dependency in dependencies.iter()
+		{
+			if let Some(externFilePath) = dependency.externFilePath()
+			{
+				command.arg("--extern").arg(format!("{}={}", dependency.crateName(), externFilePath));
+				
+				if let Some(folderPath) = Path::new(externFilePath).parent()
+				{
+					if !dependencyFolderPaths.contains(&folderPath)
+					{
+						dependencyFolderPaths.push(folderPath);
+					}
+				}
+			}
+		}
+		for folderPath in dependencyFolderPaths
+		{
+			command.arg("-L").arg(format!("dependency={}", folderPath.display()));
+		}
+		
+		command
+	}
+}
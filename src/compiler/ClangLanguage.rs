@@ -0,0 +1,36 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClangLanguage
+{
+	C,
+	
+	/// Exported functions must be declared `extern "C"`
+	CPlusPlus,
+}
+
+impl ClangLanguage
+{
+	/// The argument to clang's `-x`
+	#[inline(always)]
+	pub fn name(self) -> &'static str
+	{
+		match self
+		{
+			ClangLanguage::C => "c",
+			ClangLanguage::CPlusPlus => "c++",
+		}
+	}
+	
+	#[inline(always)]
+	fn freestandingArguments(self) -> &'static [&'static str]
+	{
+		match self
+		{
+			ClangLanguage::C => &["-ffreestanding"],
+			ClangLanguage::CPlusPlus => &["-ffreestanding", "-fno-exceptions", "-fno-rtti"],
+		}
+	}
+}
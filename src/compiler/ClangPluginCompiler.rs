@@ -0,0 +1,259 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Compiles C or C++ plugins to bit code with `clang -emit-llvm -c`, so they can be loaded alongside Rust plugins
+/// clang's diagnostics are converted to `RustcDiagnostic`s; their spans have a line and column but no byte offsets, which are zero
+pub struct ClangPluginCompiler
+{
+	pub namedTemporaryFilePathGenerator: NamedTemporaryFilePathGenerator,
+	language: ClangLanguage,
+	freestanding: bool,
+	optimisationLevel: &'static str,
+	includeFolderPaths: Vec<PathBuf>,
+	defines: Vec<(String, Option<String>)>,
+	targetTriple: Option<String>,
+	arguments: Vec<String>,
+}
+
+impl ClangPluginCompiler
+{
+	/// Bit code files are written to a scratch folder in `temporaryFolderPath`, which is removed when this is dropped
+	pub fn new(temporaryFolderPath: TemporaryFolderPath, language: ClangLanguage) -> Result<Self, CompilerError>
+	{
+		Ok
+		(
+			Self
+			{
+				namedTemporaryFilePathGenerator: NamedTemporaryFilePathGenerator::new(temporaryFolderPath, UsefulRandomFileNameGenerator::newForRustBitCode()).map_err(CompilerError::ScratchFolder)?,
+				language: language,
+				freestanding: true,
+				optimisationLevel: "3",
+				includeFolderPaths: Vec::new(),
+				defines: Vec::new(),
+				targetTriple: None,
+				arguments: Vec::new(),
+			}
+		)
+	}
+	
+	/// Defaults to true; passes `-ffreestanding` (and, for C++, `-fno-exceptions` and `-fno-rtti`), as there is no C runtime or C++ standard library in a plugin unless one is made available by its symbol resolver
+	#[inline(always)]
+	pub fn freestanding(mut self, freestanding: bool) -> Self
+	{
+		self.freestanding = freestanding;
+		self
+	}
+	
+	/// The argument to `-O`, eg `2` or `s`; defaults to `3`, as for Rust plugins
+	#[inline(always)]
+	pub fn optimisationLevel(mut self, optimisationLevel: &'static str) -> Self
+	{
+		self.optimisationLevel = optimisationLevel;
+		self
+	}
+	
+	/// Passed as `-I`, in the order added
+	#[inline(always)]
+	pub fn includeFolderPath<P: Into<PathBuf>>(mut self, includeFolderPath: P) -> Self
+	{
+		self.includeFolderPaths.push(includeFolderPath.into());
+		self
+	}
+	
+	/// Passed as `-D name` or `-D name=value`
+	#[inline(always)]
+	pub fn define(mut self, name: &str, value: Option<&str>) -> Self
+	{
+		self.defines.push((name.to_owned(), value.map(str::to_owned)));
+		self
+	}
+	
+	/// eg `x86_64-unknown-linux-musl`; by default the host's
+	#[inline(always)]
+	pub fn targetTriple(mut self, targetTriple: &str) -> Self
+	{
+		self.targetTriple = Some(targetTriple.to_owned());
+		self
+	}
+	
+	/// Passed to clang after all other arguments, eg `-std=c99` or `-Wall`
+	#[inline(always)]
+	pub fn argument(mut self, argument: &str) -> Self
+	{
+		self.arguments.push(argument.to_owned());
+		self
+	}
+	
+	pub fn compileToBuffer(&mut self, pluginName: &str, pluginSourceFilePath: &Path) -> Result<CompilerOutput<Vec<u8>>, CompilerError>
+	{
+		self.compile(pluginName, pluginSourceFilePath)?.andThen(|bitCodeFile| bitCodeFile.read())
+	}
+	
+	/// Plugins must have a manifest to be loaded with `JitContext::loadPlugins()`
+	pub fn compileWithManifest(&mut self, pluginName: &str, pluginSourceFilePath: &Path, manifest: &PluginManifest, context: &Context) -> Result<CompilerOutput<TemporaryFile>, CompilerError>
+	{
+		self.compile(pluginName, pluginSourceFilePath)?.andThen(|bitCodeFile|
+		{
+			manifest.injectIntoBitCodeFile(context, bitCodeFile.filePath()).map_err(CompilerError::Manifest)?;
+			Ok(bitCodeFile)
+		})
+	}
+	
+	/// Fails if clang exits unsuccessfully or reports any errors; warnings are returned with the bit code file, which is removed when dropped
+	pub fn compile(&mut self, pluginName: &str, pluginSourceFilePath: &Path) -> Result<CompilerOutput<TemporaryFile>, CompilerError>
+	{
+		let bitCodeFile = self.namedTemporaryFilePathGenerator.temporaryBitCodeFile(pluginName);
+		
+		let mut command = match var_os("CLANG")
+		{
+			None => Command::new("clang"),
+			Some(path) => Command::new(path),
+		};
+		
+		command
+		.arg("-x").arg(self.language.name())
+		.arg("-emit-llvm")
+		.arg("-c")
+		.arg(format!("-O{}", self.optimisationLevel))
+		.arg("-fno-color-diagnostics")
+		.arg("-fno-caret-diagnostics");
+		
+		if self.freestanding
+		{
+			command.args(self.language.freestandingArguments());
+		}
+		
+		if let Some(ref targetTriple) = self.targetTriple
+		{
+			command.arg("-target").arg(targetTriple);
+		}
+		
+		for includeFolderPath in self.includeFolderPaths.iter()
+		{
+			command.arg("-I").arg(includeFolderPath);
+		}
+		
+		for &(ref name, ref value) in self.defines.iter()
+		{
+			match *value
+			{
+				None => command.arg("-D").arg(name),
+				Some(ref value) => command.arg("-D").arg(format!("{}={}", name, value)),
+			};
+		}
+		
+		command
+		.args(&self.arguments)
+		.arg("-o").arg(bitCodeFile.filePath())
+		.arg(pluginSourceFilePath);
+		
+		let output = match command.output()
+		{
+			Err(error) => return Err(CompilerError::CouldNotRun { program: "clang", error: error }),
+			Ok(output) => output,
+		};
+		
+		let (diagnostics, mut otherOutput) = Self::parseClangOutput(&output.stderr);
+		otherOutput.push_str(&String::from_utf8_lossy(&output.stdout));
+		
+		if unlikely(!output.status.success() || diagnostics.iter().any(RustcDiagnostic::isError))
+		{
+			return Err(CompilerError::Failed { program: "clang", status: output.status, diagnostics: diagnostics, otherOutput: otherOutput });
+		}
+		
+		Ok(CompilerOutput::new(bitCodeFile, diagnostics))
+	}
+	
+	/// Diagnostics are of the form `file:line:column: level: message`; notes follow the diagnostic they belong to, so become its children
+	fn parseClangOutput(output: &[u8]) -> (Vec<RustcDiagnostic>, String)
+	{
+		let mut diagnostics: Vec<RustcDiagnostic> = Vec::new();
+		let mut otherOutput = String::new();
+		
+		for line in String::from_utf8_lossy(output).lines()
+		{
+			match Self::parseClangDiagnostic(line)
+			{
+				None =>
+				{
+					otherOutput.push_str(line);
+					otherOutput.push('\n');
+				}
+				Some(diagnostic) =>
+				{
+					if diagnostic.level == RustcDiagnosticLevel::Note
+					{
+						if let Some(previous) = diagnostics.last_mut()
+						{
+							previous.children.push(diagnostic);
+							continue;
+						}
+					}
+					diagnostics.push(diagnostic);
+				}
+			}
+		}
+		
+		(diagnostics, otherOutput)
+	}
+	
+	fn parseClangDiagnostic(line: &str) -> Option<RustcDiagnostic>
+	{
+		let levels =
+		[
+			(": fatal error: ", RustcDiagnosticLevel::Error),
+			(": error: ", RustcDiagnosticLevel::Error),
+			(": warning: ", RustcDiagnosticLevel::Warning),
+			(": note: ", RustcDiagnosticLevel::Note),
+		];
+		
+		for &(separator, ref level) in levels.iter()
+		{
+			let index = match line.find(separator)
+			{
+				None => continue,
+				Some(index) => index,
+			};
+			
+			// The location is `file:line:column`; file names may themselves contain colons
+			let location = &line[.. index];
+			let mut parts = location.rsplitn(3, ':');
+			let (columnStart, lineStart, fileName) = match (parts.next().and_then(|part| part.parse::<usize>().ok()), parts.next().and_then(|part| part.parse::<usize>().ok()), parts.next())
+			{
+				(Some(columnStart), Some(lineStart), Some(fileName)) => (columnStart, lineStart, fileName),
+				_ => continue,
+			};
+			
+			return Some
+			(
+				RustcDiagnostic
+				{
+					level: level.clone(),
+					message: line[index + separator.len() ..].to_owned(),
+					code: None,
+					spans: vec!
+					[
+						RustcDiagnosticSpan
+						{
+							fileName: fileName.to_owned(),
+							byteStart: 0,
+							byteEnd: 0,
+							lineStart: lineStart,
+							lineEnd: lineStart,
+							columnStart: columnStart,
+							columnEnd: columnStart,
+							isPrimary: true,
+							label: None,
+							suggestedReplacement: None,
+						}
+					],
+					children: Vec::new(),
+					rendered: Some(line.to_owned()),
+				}
+			);
+		}
+		
+		None
+	}
+}
@@ -0,0 +1,106 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub enum CompilerError
+{
+	/// `program` is eg `rustc` or `cargo`
+	CouldNotRun { program: &'static str, error: io::Error },
+	
+	/// `diagnostics` are all of those given, including warnings; `otherOutput` is anything else the compiler wrote, eg the output of a failed build script or linker
+	Failed { program: &'static str, status: ExitStatus, diagnostics: Vec<RustcDiagnostic>, otherOutput: String },
+	
+	/// The temporary folder or a scratch folder in it could not be created
+	ScratchFolder(io::Error),
+	
+	/// eg a bit code file could not be read or copied
+	Io { path: String, error: io::Error },
+	
+	NoBitCodeEmitted { crateName: String, folderPath: String },
+	
+	/// The plugin manifest could not be injected into the bit code
+	Manifest(LlvmError),
+}
+
+impl Display for CompilerError
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::CompilerError::*;
+		
+		match *self
+		{
+			CouldNotRun { program, ref error } => write!(f, "Could not run {} because '{}'", program, error),
+			Failed { program, status, ref diagnostics, .. } =>
+			{
+				write!(f, "{} failed with '{}'", program, status)?;
+				for diagnostic in diagnostics.iter().filter(|diagnostic| diagnostic.isError())
+				{
+					write!(f, "; {}", diagnostic)?;
+				}
+				Ok(())
+			}
+			ScratchFolder(ref error) => write!(f, "Could not create scratch folder because '{}'", error),
+			Io { ref path, ref error } => write!(f, "Could not use '{}' because '{}'", path, error),
+			NoBitCodeEmitted { ref crateName, ref folderPath } => write!(f, "No bit code was emitted for crate '{}' in '{}'", crateName, folderPath),
+			Manifest(ref error) => write!(f, "Could not inject plugin manifest because '{}'", error),
+		}
+	}
+}
+
+impl Error for CompilerError
+{
+	fn description(&self) -> &str
+	{
+		use self::CompilerError::*;
+		
+		match *self
+		{
+			CouldNotRun { .. } => "could not run compiler",
+			Failed { .. } => "compilation failed",
+			ScratchFolder(_) => "could not create scratch folder",
+			Io { ref error, .. } => error.description(),
+			NoBitCodeEmitted { .. } => "no bit code emitted",
+			Manifest(_) => "could not inject plugin manifest",
+		}
+	}
+	
+	fn cause(&self) -> Option<&Error>
+	{
+		use self::CompilerError::*;
+		
+		match *self
+		{
+			CouldNotRun { ref error, .. } => Some(error),
+			ScratchFolder(ref error) => Some(error),
+			Io { ref error, .. } => Some(error),
+			Manifest(ref error) => Some(error),
+			_ => None,
+		}
+	}
+}
+
+impl CompilerError
+{
+	/// Diagnostics the compiler gave, including warnings; empty for most errors
+	#[inline(always)]
+	pub fn diagnostics(&self) -> &[RustcDiagnostic]
+	{
+		match *self
+		{
+			CompilerError::Failed { ref diagnostics, .. } => diagnostics,
+			_ => &[],
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn io<P: AsRef<Path>>(path: P, error: io::Error) -> Self
+	{
+		CompilerError::Io
+		{
+			path: path.as_ref().display().to_string(),
+			error: error,
+		}
+	}
+}
@@ -0,0 +1,31 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// What a plugin compiler produced, eg a bit code file path or buffer, along with any warnings (and their notes) the compiler gave
+#[derive(Debug, Clone)]
+pub struct CompilerOutput<T>
+{
+	pub output: T,
+	pub diagnostics: Vec<RustcDiagnostic>,
+}
+
+impl<T> CompilerOutput<T>
+{
+	#[inline(always)]
+	pub fn new(output: T, diagnostics: Vec<RustcDiagnostic>) -> Self
+	{
+		Self
+		{
+			output: output,
+			diagnostics: diagnostics,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn andThen<U, F: FnOnce(T) -> Result<U, CompilerError>>(self, f: F) -> Result<CompilerOutput<U>, CompilerError>
+	{
+		let output = f(self.output)?;
+		Ok(CompilerOutput::new(output, self.diagnostics))
+	}
+}
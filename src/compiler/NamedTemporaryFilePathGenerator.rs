@@ -0,0 +1,55 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Generates uniquely named files in a scratch folder, which is removed, with the files, when this is dropped
+/// Note: This isn't completely secure
+pub struct NamedTemporaryFilePathGenerator
+{
+	scratchFolder: ScratchFolder,
+	usefulRandomFileNameGenerator: UsefulRandomFileNameGenerator,
+}
+
+impl NamedTemporaryFilePathGenerator
+{
+	/// Also removes scratch folders left behind in `temporaryFolderPath` by processes that are no longer running
+	#[inline(always)]
+	pub fn new(temporaryFolderPath: TemporaryFolderPath, mut usefulRandomFileNameGenerator: UsefulRandomFileNameGenerator) -> io::Result<Self>
+	{
+		let temporaryFolderPath = temporaryFolderPath.path()?;
+		
+		// Best efforts; a folder that can not be swept should not stop compilation
+		let _ = ScratchFolder::sweepStale(&temporaryFolderPath);
+		
+		let scratchFolder = ScratchFolder::create(&temporaryFolderPath, &mut usefulRandomFileNameGenerator)?;
+		
+		Ok
+		(
+			Self
+			{
+				scratchFolder,
+				usefulRandomFileNameGenerator,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	pub fn scratchFolderPath(&self) -> &Path
+	{
+		self.scratchFolder.folderPath()
+	}
+	
+	/// The file is not created
+	#[inline(always)]
+	pub fn temporaryBitCodeFile(&mut self, crateName: &str) -> TemporaryFile
+	{
+		TemporaryFile::new(self.scratchFolder.folderPath().join(self.usefulRandomFileNameGenerator.generateRustFileName(crateName)))
+	}
+	
+	/// Creates a new folder inside the scratch folder, which is removed when dropped
+	#[inline(always)]
+	pub fn createPrivateFolder(&mut self) -> io::Result<ScratchFolder>
+	{
+		ScratchFolder::create(self.scratchFolder.folderPath(), &mut self.usefulRandomFileNameGenerator)
+	}
+}
@@ -0,0 +1,33 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+pub struct RandomFileNameGenerator
+{
+	randomNumberGenerator: OsRng,
+}
+
+impl RandomFileNameGenerator
+{
+	#[inline(always)]
+	pub fn new() -> Self
+	{
+		Self
+		{
+			randomNumberGenerator: OsRng::new().expect("Could not create a random number generator"),
+		}
+	}
+	
+	#[inline(always)]
+	pub fn generateRandomFileName(&mut self, prefix: &str, separator1: &str, numberOfRandomAsciiCharacters: usize, separator2: &str, suffix: &str) -> String
+	{
+		let mut randomFileName = String::with_capacity(prefix.len() + separator1.len() + numberOfRandomAsciiCharacters + separator2.len() + suffix.len());
+		randomFileName.push_str(prefix);
+		randomFileName.push_str(separator1);
+		let randomPart: String = self.randomNumberGenerator.gen_ascii_chars().take(numberOfRandomAsciiCharacters).collect();
+		randomFileName.push_str(&randomPart);
+		randomFileName.push_str(separator2);
+		randomFileName.push_str(suffix);
+		randomFileName
+	}
+}
@@ -0,0 +1,165 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+pub struct RustPluginCompiler
+{
+	pub namedTemporaryFilePathGenerator: NamedTemporaryFilePathGenerator,
+}
+
+impl RustPluginCompiler
+{
+	/// Prepended to the source passed to `compileSource()`
+	pub const Boilerplate: &'static str = include_str!("boilerplate.plugin.rs");
+	
+	/// The file name given to diagnostics' spans that are in `Boilerplate`
+	pub const BoilerplateFileName: &'static str = "<boilerplate>";
+	
+	pub fn example(&mut self, context: &Context) -> Result<CompilerOutput<TemporaryFile>, CompilerError>
+	{
+		let mut pluginSourceFilePath = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+		pluginSourceFilePath.push("src");
+		pluginSourceFilePath.push("sample.plugin.rs");
+		
+		let manifest = PluginManifest::new("sample_plugin", env!("CARGO_PKG_VERSION"), vec!
+		[
+			PluginExport::of::<unsafe extern "C" fn() -> *const i8, _>("sample_plugin"),
+		]);
+		
+		self.compileWithManifest("sample_plugin", &pluginSourceFilePath, &manifest, context)
+	}
+	
+	/// Bit code files are written to a scratch folder in `temporaryFolderPath`, which is removed when this is dropped
+	pub fn new(temporaryFolderPath: TemporaryFolderPath) -> Result<Self, CompilerError>
+	{
+		Ok
+		(
+			Self
+			{
+				namedTemporaryFilePathGenerator: NamedTemporaryFilePathGenerator::new(temporaryFolderPath, UsefulRandomFileNameGenerator::newForRustBitCode()).map_err(CompilerError::ScratchFolder)?,
+			}
+		)
+	}
+	
+	pub fn compileToBuffer(&mut self, crateName: &str, pluginSourceFilePath: &Path) -> Result<CompilerOutput<Vec<u8>>, CompilerError>
+	{
+		self.compile(crateName, pluginSourceFilePath)?.andThen(|bitCodeFile| bitCodeFile.read())
+	}
+	
+	/// Plugins must have a manifest to be loaded with `JitContext::loadPlugins()`
+	pub fn compileWithManifest(&mut self, crateName: &str, pluginSourceFilePath: &Path, manifest: &PluginManifest, context: &Context) -> Result<CompilerOutput<TemporaryFile>, CompilerError>
+	{
+		self.compile(crateName, pluginSourceFilePath)?.andThen(|bitCodeFile|
+		{
+			manifest.injectIntoBitCodeFile(context, bitCodeFile.filePath()).map_err(CompilerError::Manifest)?;
+			Ok(bitCodeFile)
+		})
+	}
+	
+	/// Compiles `source`, which need only contain the plugin's `#[no_mangle]` functions (and anything they use), as `Boilerplate` is prepended to it
+	/// The source and bit code are written to a private temporary folder, which is removed afterwards; diagnostics' spans refer to `source`, with the file name `<crateName>.rs`, or to `Boilerplate`, with the file name `<boilerplate>`
+	pub fn compileSource(&mut self, crateName: &str, source: &str) -> Result<CompilerOutput<Vec<u8>>, CompilerError>
+	{
+		self.compileSourceInPrivateFolder(crateName, source, |_| Ok(()))
+	}
+	
+	/// As `compileSource()`, embedding a manifest so the plugin can be loaded with `JitContext::loadPlugins()`
+	pub fn compileSourceWithManifest(&mut self, crateName: &str, source: &str, manifest: &PluginManifest, context: &Context) -> Result<CompilerOutput<Vec<u8>>, CompilerError>
+	{
+		self.compileSourceInPrivateFolder(crateName, source, |bitCodeFilePath| manifest.injectIntoBitCodeFile(context, bitCodeFilePath).map_err(CompilerError::Manifest))
+	}
+	
+	fn compileSourceInPrivateFolder<F: FnOnce(&Path) -> Result<(), CompilerError>>(&mut self, crateName: &str, source: &str, afterCompilation: F) -> Result<CompilerOutput<Vec<u8>>, CompilerError>
+	{
+		let privateFolder = self.namedTemporaryFilePathGenerator.createPrivateFolder().map_err(CompilerError::ScratchFolder)?;
+		Self::compileSourceInFolder(privateFolder.folderPath(), crateName, source, afterCompilation)
+	}
+	
+	fn compileSourceInFolder<F: FnOnce(&Path) -> Result<(), CompilerError>>(folderPath: &Path, crateName: &str, source: &str, afterCompilation: F) -> Result<CompilerOutput<Vec<u8>>, CompilerError>
+	{
+		let sourceFilePath = folderPath.join(format!("{}.rs", crateName));
+		let bitCodeFile = TemporaryFile::new(folderPath.join(format!("{}.bc", crateName)));
+		
+		{
+			let mut sourceFile = File::create(&sourceFilePath).map_err(|error| CompilerError::io(&sourceFilePath, error))?;
+			sourceFile.write_all(Self::Boilerplate.as_bytes()).and_then(|_| sourceFile.write_all(source.as_bytes())).map_err(|error| CompilerError::io(&sourceFilePath, error))?;
+		}
+		
+		let sourceFileName = sourceFilePath.to_string_lossy().into_owned();
+		let relocatedFileName = format!("{}.rs", crateName);
+		let boilerplateLines = Self::Boilerplate.matches('\n').count();
+		let relocate = |diagnostics: &mut Vec<RustcDiagnostic>| for diagnostic in diagnostics.iter_mut()
+		{
+			diagnostic.relocate(&sourceFileName, &relocatedFileName, Self::BoilerplateFileName, boilerplateLines, Self::Boilerplate.len());
+		};
+		
+		let compiled = match Self::compileTo(crateName, &sourceFilePath, bitCodeFile)
+		{
+			Err(CompilerError::Failed { program, status, mut diagnostics, otherOutput }) =>
+			{
+				relocate(&mut diagnostics);
+				return Err(CompilerError::Failed { program: program, status: status, diagnostics: diagnostics, otherOutput: otherOutput });
+			}
+			Err(error) => return Err(error),
+			Ok(mut compiled) =>
+			{
+				relocate(&mut compiled.diagnostics);
+				compiled
+			}
+		};
+		
+		compiled.andThen(|bitCodeFile|
+		{
+			afterCompilation(bitCodeFile.filePath())?;
+			bitCodeFile.read()
+		})
+	}
+	
+	/// Fails if rustc exits unsuccessfully or reports any errors; warnings are returned with the bit code file, which is removed when dropped
+	pub fn compile(&mut self, crateName: &str, pluginSourceFilePath: &Path) -> Result<CompilerOutput<TemporaryFile>, CompilerError>
+	{
+		let bitCodeFile = self.namedTemporaryFilePathGenerator.temporaryBitCodeFile(crateName);
+		Self::compileTo(crateName, pluginSourceFilePath, bitCodeFile)
+	}
+	
+	/// `bitCodeFile` is dropped, so removing anything rustc wrote, if compilation fails
+	fn compileTo(crateName: &str, pluginSourceFilePath: &Path, bitCodeFile: TemporaryFile) -> Result<CompilerOutput<TemporaryFile>, CompilerError>
+	{
+		let plugin_source_file_path = pluginSourceFilePath.to_owned().into_os_string().into_string().unwrap();
+		
+		let mut command = match var_os("RUSTC")
+		{
+			None => Command::new("rustc"),
+			Some(path) => Command::new(path),
+		};
+		
+		let result = command
+		.arg("--crate-name").arg(crateName)
+		.arg("--error-format").arg("json")
+		.arg(plugin_source_file_path)
+		.arg("--crate-type").arg("bin")
+		.arg("--emit").arg("llvm-bc")
+		.arg("-C").arg("opt-level=3")
+		.arg("-C").arg("panic=abort")
+		.arg("-C").arg("lto")
+		.arg("-C").arg("relocation-model=static")
+		.arg("-o").arg(bitCodeFile.filePath())
+		.output();
+		
+		let output = match result
+		{
+			Err(error) => return Err(CompilerError::CouldNotRun { program: "rustc", error: error }),
+			Ok(output) => output,
+		};
+		
+		let (diagnostics, mut otherOutput) = RustcDiagnostic::parseRustcOutput(&output.stderr);
+		otherOutput.push_str(&String::from_utf8_lossy(&output.stdout));
+		
+		if unlikely(!output.status.success() || diagnostics.iter().any(RustcDiagnostic::isError))
+		{
+			return Err(CompilerError::Failed { program: "rustc", status: output.status, diagnostics: diagnostics, otherOutput: otherOutput });
+		}
+		
+		Ok(CompilerOutput::new(bitCodeFile, diagnostics))
+	}
+}
@@ -0,0 +1,158 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A diagnostic from rustc's `--error-format=json` output
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RustcDiagnostic
+{
+	pub level: RustcDiagnosticLevel,
+	pub message: String,
+	
+	/// eg `E0425`
+	pub code: Option<String>,
+	
+	pub spans: Vec<RustcDiagnosticSpan>,
+	
+	/// Notes and help, including suggested fixes
+	pub children: Vec<RustcDiagnostic>,
+	
+	/// rustc's human-readable rendering, if it gave one
+	pub rendered: Option<String>,
+}
+
+impl Display for RustcDiagnostic
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self.level)?;
+		if let Some(ref code) = self.code
+		{
+			write!(f, "[{}]", code)?;
+		}
+		write!(f, ": {}", self.message)?;
+		if let Some(primarySpan) = self.primarySpan()
+		{
+			write!(f, " at {}", primarySpan)?;
+		}
+		Ok(())
+	}
+}
+
+impl RustcDiagnostic
+{
+	#[inline(always)]
+	pub fn isError(&self) -> bool
+	{
+		self.level.isError()
+	}
+	
+	#[inline(always)]
+	pub fn primarySpan(&self) -> Option<&RustcDiagnosticSpan>
+	{
+		self.spans.iter().find(|span| span.isPrimary)
+	}
+	
+	/// Spans, from this diagnostic and its children, with a suggested replacement
+	pub fn suggestedFixes(&self) -> Vec<&RustcDiagnosticSpan>
+	{
+		let mut suggestedFixes: Vec<&RustcDiagnosticSpan> = self.spans.iter().filter(|span| span.suggestedReplacement.is_some()).collect();
+		for child in self.children.iter()
+		{
+			suggestedFixes.extend(child.suggestedFixes());
+		}
+		suggestedFixes
+	}
+	
+	/// Makes spans in `fileName` refer to `newFileName`, which is `fileName` without its first `lineOffset` lines (`byteOffset` bytes), eg of boilerplate
+	/// Spans wholly within those first lines are relabelled as being in `boilerplateFileName`, with their positions unchanged; spans that start in them but end after them are clipped to start at the beginning of `newFileName`
+	/// `rendered` is left as it is
+	pub(crate) fn relocate(&mut self, fileName: &str, newFileName: &str, boilerplateFileName: &str, lineOffset: usize, byteOffset: usize)
+	{
+		for span in self.spans.iter_mut().filter(|span| span.fileName == fileName)
+		{
+			if span.lineEnd <= lineOffset
+			{
+				span.fileName = boilerplateFileName.to_owned();
+				continue;
+			}
+			
+			if span.lineStart <= lineOffset
+			{
+				span.byteStart = byteOffset;
+				span.lineStart = lineOffset + 1;
+				span.columnStart = 1;
+			}
+			
+			span.fileName = newFileName.to_owned();
+			span.byteStart -= byteOffset;
+			span.byteEnd -= byteOffset;
+			span.lineStart -= lineOffset;
+			span.lineEnd -= lineOffset;
+		}
+		
+		for child in self.children.iter_mut()
+		{
+			child.relocate(fileName, newFileName, boilerplateFileName, lineOffset, byteOffset);
+		}
+	}
+	
+	/// Returns the diagnostics and any other output, eg from a linker or a panic, as text
+	pub(crate) fn parseRustcOutput(output: &[u8]) -> (Vec<Self>, String)
+	{
+		Self::parseJsonLines(output, |value| Self::fromJson(value))
+	}
+	
+	fn parseJsonLines<T, F: FnMut(&JsonValue) -> Option<T>>(output: &[u8], mut parse: F) -> (Vec<T>, String)
+	{
+		let mut parsed = Vec::new();
+		let mut otherOutput = String::new();
+		
+		for line in String::from_utf8_lossy(output).lines()
+		{
+			match from_str::<JsonValue>(line).ok().and_then(|value| parse(&value))
+			{
+				Some(item) => parsed.push(item),
+				None =>
+				{
+					otherOutput.push_str(line);
+					otherOutput.push('\n');
+				}
+			}
+		}
+		
+		(parsed, otherOutput)
+	}
+	
+	fn fromJson(diagnostic: &JsonValue) -> Option<Self>
+	{
+		let (level, message) = match (diagnostic.get("level").and_then(JsonValue::as_str), diagnostic.get("message").and_then(JsonValue::as_str))
+		{
+			(Some(level), Some(message)) => (RustcDiagnosticLevel::fromJson(level), message.to_owned()),
+			_ => return None,
+		};
+		
+		Some
+		(
+			Self
+			{
+				level: level,
+				message: message,
+				code: diagnostic.get("code").and_then(|code| code.get("code")).and_then(JsonValue::as_str).map(str::to_owned),
+				spans: Self::jsonArray(diagnostic, "spans").iter().filter_map(RustcDiagnosticSpan::fromJson).collect(),
+				children: Self::jsonArray(diagnostic, "children").iter().filter_map(Self::fromJson).collect(),
+				rendered: diagnostic.get("rendered").and_then(JsonValue::as_str).map(str::to_owned),
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn jsonArray<'a>(value: &'a JsonValue, key: &str) -> &'a [JsonValue]
+	{
+		match value.get(key).and_then(JsonValue::as_array)
+		{
+			None => &[],
+			Some(values) => values,
+		}
+	}
+}
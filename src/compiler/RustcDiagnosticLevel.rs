@@ -0,0 +1,65 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RustcDiagnosticLevel
+{
+	Error,
+	Warning,
+	Note,
+	Help,
+	InternalCompilerError,
+	
+	/// A level this crate does not know about
+	Other(String),
+}
+
+impl Display for RustcDiagnosticLevel
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::RustcDiagnosticLevel::*;
+		
+		let level = match *self
+		{
+			Error => "error",
+			Warning => "warning",
+			Note => "note",
+			Help => "help",
+			InternalCompilerError => "error: internal compiler error",
+			Other(ref level) => level,
+		};
+		
+		write!(f, "{}", level)
+	}
+}
+
+impl RustcDiagnosticLevel
+{
+	#[inline(always)]
+	fn fromJson(level: &str) -> Self
+	{
+		use self::RustcDiagnosticLevel::*;
+		
+		match level
+		{
+			"error" => Error,
+			"warning" => Warning,
+			"note" => Note,
+			"help" => Help,
+			"error: internal compiler error" => InternalCompilerError,
+			_ => Other(level.to_owned()),
+		}
+	}
+	
+	#[inline(always)]
+	pub fn isError(&self) -> bool
+	{
+		match *self
+		{
+			RustcDiagnosticLevel::Error | RustcDiagnosticLevel::InternalCompilerError => true,
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,63 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Lines and columns are one-based; columns count characters, not bytes
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RustcDiagnosticSpan
+{
+	pub fileName: String,
+	pub byteStart: usize,
+	pub byteEnd: usize,
+	pub lineStart: usize,
+	pub lineEnd: usize,
+	pub columnStart: usize,
+	pub columnEnd: usize,
+	
+	/// The span the diagnostic is about, rather than one giving context
+	pub isPrimary: bool,
+	
+	/// eg `expected i32, found &str`
+	pub label: Option<String>,
+	
+	/// The text to replace this span with to apply a suggested fix
+	pub suggestedReplacement: Option<String>,
+}
+
+impl Display for RustcDiagnosticSpan
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}:{}:{}", self.fileName, self.lineStart, self.columnStart)
+	}
+}
+
+impl RustcDiagnosticSpan
+{
+	fn fromJson(span: &JsonValue) -> Option<Self>
+	{
+		let number = |key: &str| span.get(key).and_then(JsonValue::as_u64).map(|number| number as usize);
+		let optionalString = |key: &str| span.get(key).and_then(JsonValue::as_str).map(str::to_owned);
+		
+		match (span.get("file_name").and_then(JsonValue::as_str), number("byte_start"), number("byte_end"), number("line_start"), number("line_end"), number("column_start"), number("column_end"))
+		{
+			(Some(fileName), Some(byteStart), Some(byteEnd), Some(lineStart), Some(lineEnd), Some(columnStart), Some(columnEnd)) => Some
+			(
+				Self
+				{
+					fileName: fileName.to_owned(),
+					byteStart: byteStart,
+					byteEnd: byteEnd,
+					lineStart: lineStart,
+					lineEnd: lineEnd,
+					columnStart: columnStart,
+					columnEnd: columnEnd,
+					isPrimary: span.get("is_primary").and_then(JsonValue::as_bool).unwrap_or(false),
+					label: optionalString("label"),
+					suggestedReplacement: optionalString("suggested_replacement"),
+				}
+			),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,134 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A folder that only the current user can access, which is removed, with everything in it, when dropped
+/// Whilst it exists, an exclusive `flock()` is held on a lock file inside it, so that folders left behind by processes that have since died, eg by crashing, can be told apart from those in use, even by processes in other PID namespaces; see `sweepStale()`
+#[derive(Debug)]
+pub struct ScratchFolder
+{
+	folderPath: PathBuf,
+	#[allow(dead_code)] lockFile: File,
+}
+
+impl Drop for ScratchFolder
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		// Best efforts; anything left behind is removed by a later sweep
+		// The lock is released after the folder has been removed, when `lockFile` is dropped
+		let _ = remove_dir_all(&self.folderPath);
+	}
+}
+
+impl ScratchFolder
+{
+	pub const Prefix: &'static str = "predicator-scratch-";
+	
+	const LockFileName: &'static str = ".lock";
+	
+	const TemporaryLockFileName: &'static str = ".lock-being-created";
+	
+	/// Fails rather than re-using a folder that already exists
+	/// Folders are named `predicator-scratch-<process id>-<random>`; the process identifier is only to help people looking at the folder
+	pub fn create(parentFolderPath: &Path, usefulRandomFileNameGenerator: &mut UsefulRandomFileNameGenerator) -> io::Result<Self>
+	{
+		let processIdentifier = unsafe { getpid() };
+		let folderPath = parentFolderPath.join(usefulRandomFileNameGenerator.generateFolderName(&format!("{}{}", Self::Prefix, processIdentifier)));
+		DirBuilder::new().mode(0o700).create(&folderPath)?;
+		
+		let lockFile = match Self::createAndLockLockFile(&folderPath)
+		{
+			Err(error) =>
+			{
+				let _ = remove_dir_all(&folderPath);
+				return Err(error);
+			}
+			Ok(lockFile) => lockFile,
+		};
+		
+		Ok
+		(
+			Self
+			{
+				folderPath: folderPath,
+				lockFile: lockFile,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	pub fn folderPath(&self) -> &Path
+	{
+		&self.folderPath
+	}
+	
+	/// Removes scratch folders in `parentFolderPath` whose lock can be acquired, ie those whose `ScratchFolder` is no longer alive in any process; returns the number removed
+	/// Folders without a lock file are left alone, as they may be being created
+	pub fn sweepStale(parentFolderPath: &Path) -> io::Result<usize>
+	{
+		let mut numberRemoved = 0;
+		
+		for entry in read_dir(parentFolderPath)?
+		{
+			let entry = entry?;
+			
+			if !entry.file_type()?.is_dir()
+			{
+				continue;
+			}
+			
+			if !entry.file_name().to_string_lossy().starts_with(Self::Prefix)
+			{
+				continue;
+			}
+			
+			let folderPath = entry.path();
+			let lockFile = match File::open(folderPath.join(Self::LockFileName))
+			{
+				Err(_) => continue,
+				Ok(lockFile) => lockFile,
+			};
+			
+			if Self::lock(&lockFile).is_err()
+			{
+				continue;
+			}
+			
+			// Removed whilst holding the lock, so that no other process sweeps it at the same time
+			if remove_dir_all(&folderPath).is_ok()
+			{
+				numberRemoved += 1;
+			}
+		}
+		
+		Ok(numberRemoved)
+	}
+	
+	/// The lock file is locked under a temporary name and then renamed, so that a sweep never sees an unlocked lock file in a folder that is being created
+	/// If this fails, anything left behind is removed with the folder by `create()`
+	#[inline(always)]
+	fn createAndLockLockFile(folderPath: &Path) -> io::Result<File>
+	{
+		let temporaryLockFilePath = folderPath.join(Self::TemporaryLockFileName);
+		let lockFile = OpenOptions::new().write(true).create_new(true).mode(0o600).open(&temporaryLockFilePath)?;
+		Self::lock(&lockFile)?;
+		rename(&temporaryLockFilePath, folderPath.join(Self::LockFileName))?;
+		Ok(lockFile)
+	}
+	
+	/// Fails (with `EWOULDBLOCK`) rather than waiting if another open file holds the lock; released when `lockFile` is closed
+	#[inline(always)]
+	fn lock(lockFile: &File) -> io::Result<()>
+	{
+		if unsafe { flock(lockFile.as_raw_fd(), LOCK_EX | LOCK_NB) } == 0
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(io::Error::last_os_error())
+		}
+	}
+}
@@ -0,0 +1,66 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A file, eg of bit code, that is removed when dropped unless moved elsewhere with `keep()`
+/// Files created by a plugin compiler are in its scratch folder, so are also removed when the compiler is dropped
+#[derive(Debug)]
+pub struct TemporaryFile
+{
+	filePath: PathBuf,
+}
+
+impl Drop for TemporaryFile
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		// The file may never have been created, eg if compilation failed
+		let _ = remove_file(&self.filePath);
+	}
+}
+
+impl TemporaryFile
+{
+	#[inline(always)]
+	pub(crate) fn new(filePath: PathBuf) -> Self
+	{
+		Self
+		{
+			filePath: filePath,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn filePath(&self) -> &Path
+	{
+		&self.filePath
+	}
+	
+	pub fn read(&self) -> Result<Vec<u8>, CompilerError>
+	{
+		let read = || -> io::Result<Vec<u8>>
+		{
+			let mut file = File::open(&self.filePath)?;
+			let mut buffer = Vec::with_capacity(1024 * 64);
+			file.read_to_end(&mut buffer)?;
+			Ok(buffer)
+		};
+		read().map_err(|error| CompilerError::io(&self.filePath, error))
+	}
+	
+	/// Moves the file to `filePath`, outside of the scratch folder it is in, so that it is not removed when this or the compiler that created it is dropped
+	/// The file is copied instead if it can not be renamed, eg because `filePath` is on another file system
+	pub fn keep<P: AsRef<Path>>(self, filePath: P) -> Result<(), CompilerError>
+	{
+		let filePath = filePath.as_ref();
+		
+		if rename(&self.filePath, filePath).is_err()
+		{
+			copy(&self.filePath, filePath).map_err(|error| CompilerError::io(filePath, error))?;
+		}
+		
+		// Dropping `self` removes the original if it was copied
+		Ok(())
+	}
+}
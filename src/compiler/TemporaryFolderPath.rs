@@ -0,0 +1,53 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+pub enum TemporaryFolderPath
+{
+	TempDir,
+	HomeFallingBackToTempDir,
+	PathUnderHomeFallingBackToTempDir(PathBuf),
+	OriginalOurDir,
+	Other(PathBuf),
+}
+
+impl TemporaryFolderPath
+{
+	/// Creates the folder if it does not exist
+	#[inline(always)]
+	pub fn path(&self) -> io::Result<PathBuf>
+	{
+		use self::TemporaryFolderPath::*;
+		
+		let path = match *self
+		{
+			TempDir => temp_dir(),
+			HomeFallingBackToTempDir => match var_os("HOME")
+			{
+				Some(home) => PathBuf::from(home),
+				None => temp_dir(),
+			},
+			PathUnderHomeFallingBackToTempDir(ref relativePath) => match var_os("HOME")
+			{
+				Some(home) =>
+				{
+					let path = PathBuf::from(home);
+					path.join(relativePath.clone())
+				},
+				None => temp_dir(),
+			},
+			OriginalOurDir => PathBuf::from(env!("OUT_DIR")),
+			Other(ref path) => path.clone(),
+		};
+		
+		if !path.exists()
+		{
+			if let Err(error) = create_dir_all(&path)
+			{
+				return Err(io::Error::new(error.kind(), format!("Could not create temporary folder '{}' because '{}'", path.display(), error)));
+			}
+		}
+		
+		Ok(path)
+	}
+}
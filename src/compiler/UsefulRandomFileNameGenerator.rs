@@ -0,0 +1,47 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+pub struct UsefulRandomFileNameGenerator
+{
+	randomFileNameGenerator: RandomFileNameGenerator,
+	separator1: String,
+	numberOfRandomAsciiCharacters: usize,
+	separator2: String,
+	suffix: String,
+}
+
+impl UsefulRandomFileNameGenerator
+{
+	#[inline(always)]
+	pub fn newForRustBitCode() -> Self
+	{
+		Self::new("-".to_owned(), 16, ".".to_owned(), "bc".to_owned())
+	}
+	
+	#[inline(always)]
+	pub fn new(separator1: String, numberOfRandomAsciiCharacters: usize, separator2: String, suffix: String) -> Self
+	{
+		Self
+		{
+			randomFileNameGenerator: RandomFileNameGenerator::new(),
+			separator1: separator1,
+			numberOfRandomAsciiCharacters: numberOfRandomAsciiCharacters,
+			separator2: separator2,
+			suffix: suffix,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn generateRustFileName(&mut self, crateName: &str) -> String
+	{
+		self.randomFileNameGenerator.generateRandomFileName(crateName, &self.separator1, self.numberOfRandomAsciiCharacters, &self.separator2, &self.suffix)
+	}
+	
+	/// As `generateRustFileName()`, but without the suffix
+	#[inline(always)]
+	pub fn generateFolderName(&mut self, crateName: &str) -> String
+	{
+		self.randomFileNameGenerator.generateRandomFileName(crateName, &self.separator1, self.numberOfRandomAsciiCharacters, "", "")
+	}
+}
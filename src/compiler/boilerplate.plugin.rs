@@ -0,0 +1,38 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+// Prepended by `RustPluginCompiler::compileSource()`
+
+
+#![no_std]
+#![allow(unused)]
+#![feature(lang_items)]
+#![feature(libc)]
+#![no_main]
+
+
+extern crate libc;
+
+
+#[lang = "panic_fmt"]
+fn panic_fmt() -> !
+{
+	loop
+	{
+	}
+}
+
+#[lang = "eh_personality"]
+extern fn eh_personality()
+{
+}
+
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern fn main(argc: isize, argv: *const *const u8) -> isize
+{
+	0
+}
+
+
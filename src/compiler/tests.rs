@@ -0,0 +1,237 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+use super::*;
+use ::llvm::MemoryBufferCreator;
+use ::llvm::ModuleSourceCodeType;
+use ::llvm::SuperContext;
+
+
+fn writeFile(filePath: &Path, contents: &str)
+{
+	let mut file = File::create(filePath).expect("Could not create file");
+	file.write_all(contents.as_bytes()).expect("Could not write file");
+}
+
+#[test]
+#[ignore] // Needs cargo and a nightly rustc on the PATH; run with `cargo test -- --ignored`
+fn cargoPluginCompilerIncludesDependenciesInBitCode()
+{
+	let scratchFolder = ScratchFolder::create(&temp_dir(), &mut UsefulRandomFileNameGenerator::newForRustBitCode()).expect("Could not create scratch folder");
+	
+	let dependencyFolderPath = scratchFolder.folderPath().join("dependency");
+	create_dir_all(&dependencyFolderPath).expect("Could not create dependency folder");
+	writeFile(&dependencyFolderPath.join("Cargo.toml"), "[package]\nname = \"plugin-dependency\"\nversion = \"0.0.0\"\n\n[lib]\npath = \"lib.rs\"\n");
+	writeFile(&dependencyFolderPath.join("lib.rs"), "#![no_std]\n\n#[inline(never)]\npub fn add_one(value: u32) -> u32\n{\n\tvalue + 1\n}\n");
+	
+	let pluginFolderPath = scratchFolder.folderPath().join("plugin");
+	create_dir_all(&pluginFolderPath).expect("Could not create plugin folder");
+	writeFile(&pluginFolderPath.join("Cargo.toml"), "[package]\nname = \"plugin\"\nversion = \"0.0.0\"\n\n[lib]\npath = \"lib.rs\"\n\n[dependencies]\nplugin-dependency = { path = \"../dependency\" }\n");
+	writeFile(&pluginFolderPath.join("lib.rs"), &format!("{}\nextern crate plugin_dependency;\n\n#[no_mangle]\npub extern fn plugin_add_one(value: u32) -> u32\n{{\n\tplugin_dependency::add_one(value)\n}}\n", RustPluginCompiler::Boilerplate));
+	
+	let mut compiler = CargoPluginCompiler::new(TemporaryFolderPath::TempDir).expect("Could not create compiler").targetFolderPath(scratchFolder.folderPath().join("target"));
+	let compilerOutput = compiler.compile("plugin", &pluginFolderPath).expect("Could not compile plugin");
+	
+	let context = SuperContext::threadLocal().newContext().expect("Could not create context");
+	let module = ModuleSourceCodeType::BitCode.createVerifiedModule(&context, &MemoryBufferCreator::File(compilerOutput.output.filePath())).expect("Could not load bit code");
+	
+	let definesPluginFunction = match module.namedFunction("plugin_add_one")
+	{
+		None => false,
+		Some(function) => !function.isDeclaration(),
+	};
+	assert!(definesPluginFunction, "plugin_add_one() is not defined in the bit code");
+	
+	for import in module.undefinedImports().expect("Could not list undefined imports")
+	{
+		assert!(!import.name.to_string_lossy().contains("add_one"), "the dependency's add_one() is not in the bit code: {:?}", import.name);
+	}
+}
+
+const UnusedVariableWarning: &'static str = r#"{"message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[{"file_name":"plugin.rs","byte_start":20,"byte_end":21,"line_start":2,"line_end":2,"column_start":6,"column_end":7,"is_primary":true,"text":[],"label":null,"suggested_replacement":null,"expansion":null}],"children":[{"message":"consider using `_x` instead","code":null,"level":"help","spans":[{"file_name":"plugin.rs","byte_start":20,"byte_end":21,"line_start":2,"line_end":2,"column_start":6,"column_end":7,"is_primary":true,"text":[],"label":null,"suggested_replacement":"_x","expansion":null}],"children":[],"rendered":null}],"rendered":"warning: unused variable: `x`\n"}"#;
+
+#[test]
+fn parseRustcOutputParsesDiagnosticsAndKeepsOtherOutput()
+{
+	let output = format!("{}\nerror: linking with `cc` failed\n{{\"message\":\"no level\"}}\n", UnusedVariableWarning);
+	
+	let (diagnostics, otherOutput) = RustcDiagnostic::parseRustcOutput(output.as_bytes());
+	
+	assert_eq!(otherOutput, "error: linking with `cc` failed\n{\"message\":\"no level\"}\n");
+	assert_eq!(diagnostics.len(), 1);
+	
+	let diagnostic = &diagnostics[0];
+	assert_eq!(diagnostic.level, RustcDiagnosticLevel::Warning);
+	assert!(!diagnostic.isError());
+	assert_eq!(diagnostic.message, "unused variable: `x`");
+	assert_eq!(diagnostic.code, Some("unused_variables".to_owned()));
+	assert_eq!(diagnostic.rendered, Some("warning: unused variable: `x`\n".to_owned()));
+	assert_eq!(diagnostic.to_string(), "warning[unused_variables]: unused variable: `x` at plugin.rs:2:6");
+	
+	assert_eq!(diagnostic.children.len(), 1);
+	assert_eq!(diagnostic.children[0].level, RustcDiagnosticLevel::Help);
+	
+	let suggestedFixes = diagnostic.suggestedFixes();
+	assert_eq!(suggestedFixes.len(), 1);
+	assert_eq!(suggestedFixes[0].suggestedReplacement, Some("_x".to_owned()));
+}
+
+#[test]
+fn parseCargoOutputParsesMessages()
+{
+	let output = format!
+	(
+		"{}\n{}\n{{\"reason\":\"compiler-message\",\"package_id\":\"plugin 0.0.0 (path+file:///tmp/plugin)\",\"target\":{{\"kind\":[\"lib\"],\"name\":\"plugin\",\"src_path\":\"/tmp/plugin/lib.rs\"}},\"message\":{}}}\n{}\n   Compiling plugin v0.0.0\n",
+		r#"{"reason":"compiler-artifact","package_id":"plugin-dependency 0.1.0 (path+file:///tmp/dependency)","target":{"kind":["lib"],"name":"plugin-dependency","src_path":"/tmp/dependency/lib.rs"},"features":["default"],"filenames":["/tmp/target/release/deps/libplugin_dependency-0123456789abcdef.rlib"],"fresh":false}"#,
+		r#"{"reason":"build-script-executed","package_id":"plugin 0.0.0 (path+file:///tmp/plugin)","linked_libs":[],"linked_paths":["native=/tmp/out"],"cfgs":["has_feature"]}"#,
+		UnusedVariableWarning,
+		r#"{"reason":"some-future-reason"}"#
+	);
+	
+	let (messages, otherOutput) = CargoMessage::parseCargoOutput(output.as_bytes());
+	
+	assert_eq!(otherOutput, "   Compiling plugin v0.0.0\n");
+	assert_eq!(messages.len(), 4);
+	
+	match messages[0]
+	{
+		CargoMessage::CompilerArtifact(ref artifact) =>
+		{
+			assert_eq!(artifact.crateName(), "plugin_dependency");
+			assert_eq!(artifact.packageNameAndVersion(), ("plugin-dependency", "0.1.0"));
+			assert_eq!(artifact.features, vec!["default".to_owned()]);
+			assert_eq!(artifact.externFilePath(), Some("/tmp/target/release/deps/libplugin_dependency-0123456789abcdef.rlib"));
+		}
+		ref message => panic!("Expected a compiler artifact, not {:?}", message),
+	}
+	
+	assert_eq!(messages[1], CargoMessage::BuildScriptExecuted(CargoBuildScriptOutput { packageIdentifier: "plugin 0.0.0 (path+file:///tmp/plugin)".to_owned(), linkedPaths: vec!["native=/tmp/out".to_owned()], cfgs: vec!["has_feature".to_owned()] }));
+	
+	match messages[2]
+	{
+		CargoMessage::CompilerMessage { ref packageIdentifier, ref sourceFilePath, ref diagnostic } =>
+		{
+			assert_eq!(packageIdentifier, "plugin 0.0.0 (path+file:///tmp/plugin)");
+			assert_eq!(sourceFilePath, "/tmp/plugin/lib.rs");
+			assert_eq!(diagnostic.message, "unused variable: `x`");
+		}
+		ref message => panic!("Expected a compiler message, not {:?}", message),
+	}
+	
+	assert_eq!(messages[3], CargoMessage::Other);
+	
+	assert_eq!(CargoMessage::diagnostics(&messages).len(), 1);
+}
+
+#[test]
+fn cargoArtifactHasNoExternFilePathForBinaries()
+{
+	let (messages, _) = CargoMessage::parseCargoOutput(br#"{"reason":"compiler-artifact","package_id":"plugin 0.0.0 (path+file:///tmp/plugin)","target":{"kind":["bin"],"name":"plugin","src_path":"/tmp/plugin/main.rs"},"features":[],"filenames":["/tmp/target/release/plugin"],"fresh":true}"#);
+	
+	match messages[0]
+	{
+		CargoMessage::CompilerArtifact(ref artifact) => assert_eq!(artifact.externFilePath(), None),
+		ref message => panic!("Expected a compiler artifact, not {:?}", message),
+	}
+}
+
+fn span(fileName: &str, byteStart: usize, byteEnd: usize, lineStart: usize, lineEnd: usize, columnStart: usize) -> RustcDiagnosticSpan
+{
+	RustcDiagnosticSpan
+	{
+		fileName: fileName.to_owned(),
+		byteStart: byteStart,
+		byteEnd: byteEnd,
+		lineStart: lineStart,
+		lineEnd: lineEnd,
+		columnStart: columnStart,
+		columnEnd: 3,
+		isPrimary: true,
+		label: None,
+		suggestedReplacement: None,
+	}
+}
+
+fn diagnostic(spans: Vec<RustcDiagnosticSpan>, children: Vec<RustcDiagnostic>) -> RustcDiagnostic
+{
+	RustcDiagnostic
+	{
+		level: RustcDiagnosticLevel::Warning,
+		message: "message".to_owned(),
+		code: None,
+		spans: spans,
+		children: children,
+		rendered: None,
+	}
+}
+
+#[test]
+fn relocateMovesSpansAfterTheBoilerplateIntoTheNewFile()
+{
+	let mut relocated = diagnostic(vec![span("plugin.rs", 250, 255, 12, 12, 7)], vec![diagnostic(vec![span("plugin.rs", 300, 310, 14, 15, 2)], vec![])]);
+	relocated.relocate("plugin.rs", "source.rs", "<boilerplate>", 10, 200);
+	
+	assert_eq!(relocated.spans, vec![span("source.rs", 50, 55, 2, 2, 7)]);
+	assert_eq!(relocated.children[0].spans, vec![span("source.rs", 100, 110, 4, 5, 2)]);
+}
+
+#[test]
+fn relocateRelabelsSpansInTheBoilerplate()
+{
+	let mut relocated = diagnostic(vec![span("plugin.rs", 30, 60, 3, 10, 5)], vec![]);
+	relocated.relocate("plugin.rs", "source.rs", "<boilerplate>", 10, 200);
+	
+	assert_eq!(relocated.spans, vec![span("<boilerplate>", 30, 60, 3, 10, 5)]);
+}
+
+#[test]
+fn relocateClipsSpansStraddlingTheBoilerplate()
+{
+	let mut relocated = diagnostic(vec![span("plugin.rs", 180, 260, 9, 12, 5)], vec![]);
+	relocated.relocate("plugin.rs", "source.rs", "<boilerplate>", 10, 200);
+	
+	assert_eq!(relocated.spans, vec![span("source.rs", 0, 60, 1, 2, 1)]);
+}
+
+#[test]
+fn relocateLeavesSpansInOtherFilesAlone()
+{
+	let mut relocated = diagnostic(vec![span("other.rs", 250, 255, 12, 12, 7)], vec![]);
+	relocated.relocate("plugin.rs", "source.rs", "<boilerplate>", 10, 200);
+	
+	assert_eq!(relocated.spans, vec![span("other.rs", 250, 255, 12, 12, 7)]);
+}
+
+#[test]
+fn parseClangOutputParsesDiagnosticsWithColonsInFileNames()
+{
+	let (diagnostics, otherOutput) = ClangPluginCompiler::parseClangOutput(b"/tmp/a:b/plugin.c:12:5: warning: unused variable 'x' [-Wunused-variable]\n/tmp/a:b/plugin.c:20:1: fatal error: expected '}'\n2 warnings generated.\n");
+	
+	assert_eq!(otherOutput, "2 warnings generated.\n");
+	assert_eq!(diagnostics.len(), 2);
+	
+	assert_eq!(diagnostics[0].level, RustcDiagnosticLevel::Warning);
+	assert_eq!(diagnostics[0].message, "unused variable 'x' [-Wunused-variable]");
+	assert_eq!(diagnostics[0].spans, vec![RustcDiagnosticSpan { fileName: "/tmp/a:b/plugin.c".to_owned(), byteStart: 0, byteEnd: 0, lineStart: 12, lineEnd: 12, columnStart: 5, columnEnd: 5, isPrimary: true, label: None, suggestedReplacement: None }]);
+	
+	assert_eq!(diagnostics[1].level, RustcDiagnosticLevel::Error);
+	assert_eq!(diagnostics[1].primarySpan().map(|span| span.to_string()), Some("/tmp/a:b/plugin.c:20:1".to_owned()));
+}
+
+#[test]
+fn parseClangOutputMakesNotesChildrenOfThePrecedingDiagnostic()
+{
+	let (diagnostics, otherOutput) = ClangPluginCompiler::parseClangOutput(b"plugin.c:3:1: note: orphaned\nplugin.c:5:2: warning: implicit declaration of function 'f'\nplugin.c:1:1: note: 'f' was meant\nnot a diagnostic: error: here\n");
+	
+	assert_eq!(otherOutput, "not a diagnostic: error: here\n");
+	assert_eq!(diagnostics.len(), 2);
+	
+	assert_eq!(diagnostics[0].level, RustcDiagnosticLevel::Note);
+	assert_eq!(diagnostics[0].message, "orphaned");
+	assert!(diagnostics[0].children.is_empty());
+	
+	assert_eq!(diagnostics[1].level, RustcDiagnosticLevel::Warning);
+	assert_eq!(diagnostics[1].children.len(), 1);
+	assert_eq!(diagnostics[1].children[0].message, "'f' was meant");
+}
@@ -15,12 +15,15 @@ extern crate libc;
 pub extern crate llvm_sys;
 #[macro_use] extern crate maplit;
 extern crate rand;
+extern crate serde_json;
+extern crate sha2;
 #[macro_use] pub extern crate rust_extra;
 
 
 use ::libc::c_char;
 use ::libc::c_void;
 use ::libc::free;
+use ::llvm::LlvmError;
 use ::std::ffi::CStr;
 use ::std::ffi::CString;
 
@@ -41,7 +44,7 @@ cpp!
 	}
 }
 
-pub(crate) fn llvmHostCpuName() -> Result<CString, String>
+pub(crate) fn llvmHostCpuName() -> Result<CString, LlvmError>
 {
 	unsafe
 	{
@@ -52,7 +55,7 @@ pub(crate) fn llvmHostCpuName() -> Result<CString, String>
 		
 		if result.is_null()
 		{
-			Err("Could not obtain host CPU features".to_owned())
+			Err(LlvmError::targetInitialisation("Could not obtain host CPU name"))
 		}
 		else
 		{
@@ -64,7 +67,7 @@ pub(crate) fn llvmHostCpuName() -> Result<CString, String>
 	}
 }
 
-pub(crate) fn llvmHostCpuFeatures() -> Result<CString, String>
+pub(crate) fn llvmHostCpuFeatures() -> Result<CString, LlvmError>
 {
 	unsafe
 	{
@@ -90,7 +93,7 @@ pub(crate) fn llvmHostCpuFeatures() -> Result<CString, String>
 		
 		if result.is_null()
 		{
-			Err("Could not obtain host CPU features".to_owned())
+			Err(LlvmError::targetInitialisation("Could not obtain host CPU features"))
 		}
 		else
 		{
@@ -0,0 +1,69 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Tries `first`, then `second` if `first` did not find the symbol
+/// Longer chains can be made with `then()`, eg `ChainedSymbolResolver::new(hostFunctions, loadedModules).then(ProcessSymbolResolver)`
+#[derive(Debug, Default, Clone)]
+pub struct ChainedSymbolResolver<First: SymbolResolver, Second: SymbolResolver>
+{
+	first: First,
+	second: Second,
+}
+
+impl<First: SymbolResolver, Second: SymbolResolver> SymbolResolver for ChainedSymbolResolver<First, Second>
+{
+	#[inline(always)]
+	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64
+	{
+		let address = self.first.resolveSymbolAddress(symbolName);
+		if address != SymbolNotFound
+		{
+			address
+		}
+		else
+		{
+			self.second.resolveSymbolAddress(symbolName)
+		}
+	}
+	
+	#[inline(always)]
+	fn takeForbiddenSymbols(&self) -> Vec<CString>
+	{
+		let mut forbiddenSymbols = self.first.takeForbiddenSymbols();
+		forbiddenSymbols.extend(self.second.takeForbiddenSymbols());
+		forbiddenSymbols
+	}
+	
+	#[inline(always)]
+	fn isForbidden(&self, symbolName: &CStr) -> bool
+	{
+		self.first.isForbidden(symbolName) || self.second.isForbidden(symbolName)
+	}
+	
+	#[inline(always)]
+	fn attachedToOrcJitStack(&self, orcJitStackReference: LLVMOrcJITStackRef)
+	{
+		self.first.attachedToOrcJitStack(orcJitStackReference);
+		self.second.attachedToOrcJitStack(orcJitStackReference)
+	}
+}
+
+impl<First: SymbolResolver, Second: SymbolResolver> ChainedSymbolResolver<First, Second>
+{
+	#[inline(always)]
+	pub fn new(first: First, second: Second) -> Self
+	{
+		Self
+		{
+			first: first,
+			second: second,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn then<Next: SymbolResolver>(self, next: Next) -> ChainedSymbolResolver<Self, Next>
+	{
+		ChainedSymbolResolver::new(self, next)
+	}
+}
@@ -0,0 +1,39 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CompilationMode
+{
+	/// Every function is compiled when the module is loaded
+	Eager,
+	
+	/// Each function is compiled when it is first called
+	/// Function pointers point to ORC indirect stubs, which trigger a compile callback on first call and are then updated to point to the compiled function
+	/// Only supported on targets ORC has a compile callback manager for (eg x86-64)
+	Lazy,
+}
+
+impl Default for CompilationMode
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		CompilationMode::Eager
+	}
+}
+
+impl CompilationMode
+{
+	#[inline(always)]
+	pub(crate) fn addToOrcJitStack(&self, orcJitStackReference: LLVMOrcJITStackRef, moduleReference: LLVMModuleRef, symbolResolver: LLVMOrcSymbolResolverFn, symbolResolverContext: *mut c_void) -> LLVMOrcModuleHandle
+	{
+		use self::CompilationMode::*;
+		
+		match *self
+		{
+			Eager => unsafe { LLVMOrcAddEagerlyCompiledIR(orcJitStackReference, moduleReference, symbolResolver, symbolResolverContext) },
+			Lazy => unsafe { LLVMOrcAddLazilyCompiledIR(orcJitStackReference, moduleReference, symbolResolver, symbolResolverContext) },
+		}
+	}
+}
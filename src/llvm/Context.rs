@@ -71,19 +71,19 @@ impl<Key: ToReference<Value> + Clone, Value: Copy> ContextCache<Key, Value>
 impl Context
 {
 	#[inline(always)]
-	pub fn new(enumAttributeIdentifierCache: EnumAttributeIdentifierCache) -> Result<Self, String>
+	pub fn new(enumAttributeIdentifierCache: EnumAttributeIdentifierCache) -> Result<Self, LlvmError>
 	{
 		let reference = unsafe { LLVMContextCreate() };
 		if reference.is_null()
 		{
-			Err("Could not create context".to_owned())
+			Err(LlvmError::CouldNotCreate("context"))
 		}
 		else
 		{
 			let mut this = Self
 			{
 				reference: reference,
-				dropWrapper: Rc::new(ContextDropWrapper(reference)),
+				dropWrapper: Rc::new(ContextDropWrapper::new(reference)),
 				enumAttributeIdentifierCache: enumAttributeIdentifierCache,
 				typeRefCache: ContextCache::default(),
 				constantCache: ContextCache::default(),
@@ -120,6 +120,13 @@ impl Context
 		}
 	}
 	
+	/// Removes and returns the diagnostics LLVM has reported for this context (and its modules) since they were last taken, or since the last parse, verification or link began
+	#[inline(always)]
+	pub fn takeDiagnostics(&self) -> Vec<Diagnostic>
+	{
+		self.dropWrapper.takeDiagnostics()
+	}
+	
 	#[inline(always)]
 	pub fn metadataKind_tbaa(&self) -> u32
 	{
@@ -291,13 +298,13 @@ impl Context
 		self.parameterAttributeCache.getOrAdd(attribute, self)
 	}
 	
-	pub fn createModule(&self, name: &str, identifier: &str, targetTriple: &CStr, targetMachineDataLayout: &TargetMachineDataLayout, inlineAssembler: Option<&str>) -> Result<Module, String>
+	pub fn createModule(&self, name: &str, identifier: &str, targetTriple: &CStr, targetMachineDataLayout: &TargetMachineDataLayout, inlineAssembler: Option<&str>) -> Result<Module, LlvmError>
 	{
 		let cName = CString::new(name).expect("name contains embedded NULs");
 		let reference = unsafe { LLVMModuleCreateWithNameInContext(cName.as_ptr(), self.reference) };
 		if unlikely(reference.is_null())
 		{
-			Err(format!("Could not create a new module with name '{:?}'", cName))
+			Err(LlvmError::CouldNotCreate("module"))
 		}
 		else
 		{
@@ -327,13 +334,15 @@ impl Context
 	}
 	
 	#[inline(always)]
-	pub fn loadBitCodeIntoModule<'a>(&self, memoryBuffer: &MemoryBuffer<'a>) -> Result<Module, String>
+	pub fn loadBitCodeIntoModule<'a>(&self, memoryBuffer: &MemoryBuffer<'a>) -> Result<Module, LlvmError>
 	{
+		self.dropWrapper.clearDiagnostics();
+		
 		let mut reference = unsafe { uninitialized() };
 		let boolean = unsafe { LLVMGetBitcodeModuleInContext2(self.reference, memoryBuffer.reference, &mut reference) };
 		if unlikely(boolean != 0)
 		{
-			Err("Could not load bit code into module".to_owned())
+			Err(LlvmError::BitCodeParse { diagnostics: self.takeDiagnostics() })
 		}
 		else
 		{
@@ -350,13 +359,15 @@ impl Context
 	}
 	
 	#[inline(always)]
-	pub fn parseBitCodeIntoModule<'a>(&self, memoryBuffer: &MemoryBuffer<'a>) -> Result<Module, String>
+	pub fn parseBitCodeIntoModule<'a>(&self, memoryBuffer: &MemoryBuffer<'a>) -> Result<Module, LlvmError>
 	{
+		self.dropWrapper.clearDiagnostics();
+		
 		let mut reference = unsafe { uninitialized() };
 		let boolean = unsafe { LLVMParseBitcodeInContext2(self.reference, memoryBuffer.reference, &mut reference) };
 		if unlikely(boolean != 0)
 		{
-			Err("Could not parse bit code into module".to_owned())
+			Err(LlvmError::BitCodeParse { diagnostics: self.takeDiagnostics() })
 		}
 		else
 		{
@@ -372,13 +383,15 @@ impl Context
 		}
 	}
 	
-	pub fn parseTextualIntermediateRepresentationIntoModule<'a>(&self, memoryBuffer: &MemoryBuffer<'a>) -> Result<Module, String>
+	pub fn parseTextualIntermediateRepresentationIntoModule<'a>(&self, memoryBuffer: &MemoryBuffer<'a>) -> Result<Module, LlvmError>
 	{
+		self.dropWrapper.clearDiagnostics();
+		
 		let mut reference = unsafe { uninitialized() };
 		
 		let mut errorMessage = null_mut();
 		let boolean = unsafe { LLVMParseIRInContext(self.reference, memoryBuffer.reference, &mut reference, &mut errorMessage) };
-		handle_boolean_and_error_message!(boolean, errorMessage, LLVMParseIRInContext);
+		handle_boolean_and_error_message!(boolean, errorMessage, LLVMParseIRInContext, |message| LlvmError::intermediateRepresentationParse(message, self.takeDiagnostics()));
 		
 		Ok
 		(
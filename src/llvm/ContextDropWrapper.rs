@@ -2,13 +2,75 @@
 // Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
 
 
-pub(crate) struct ContextDropWrapper(LLVMContextRef);
+pub(crate) struct ContextDropWrapper
+{
+	reference: LLVMContextRef,
+	
+	// Boxed so that the address given to LLVMContextSetDiagnosticHandler is stable; lives exactly as long as the LLVM context
+	diagnostics: Box<RefCell<Vec<Diagnostic>>>,
+}
 
 impl Drop for ContextDropWrapper
 {
 	#[inline(always)]
 	fn drop(&mut self)
 	{
-		unsafe { LLVMContextDispose(self.0) }
+		unsafe { LLVMContextDispose(self.reference) }
+	}
+}
+
+impl ContextDropWrapper
+{
+	/// Installs a diagnostic handler, so LLVM no longer prints diagnostics to stderr (or exits on errors)
+	#[inline(always)]
+	pub(crate) fn new(reference: LLVMContextRef) -> Self
+	{
+		let this = Self
+		{
+			reference: reference,
+			diagnostics: Box::new(RefCell::new(Vec::new())),
+		};
+		
+		let diagnosticContext = (&*this.diagnostics) as *const RefCell<Vec<Diagnostic>> as *mut c_void;
+		unsafe { LLVMContextSetDiagnosticHandler(reference, Self::diagnosticHandler, diagnosticContext) };
+		
+		this
+	}
+	
+	#[inline(always)]
+	pub(crate) fn takeDiagnostics(&self) -> Vec<Diagnostic>
+	{
+		replace(&mut *self.diagnostics.borrow_mut(), Vec::new())
+	}
+	
+	/// Called at the start of each parse, verification or link, so that only the diagnostics it reports are returned with its error and the buffer does not grow for as long as the context lives
+	#[inline(always)]
+	pub(crate) fn clearDiagnostics(&self)
+	{
+		self.diagnostics.borrow_mut().clear()
+	}
+	
+	extern "C" fn diagnosticHandler(diagnosticInfo: LLVMDiagnosticInfoRef, diagnosticContext: *mut c_void)
+	{
+		let diagnostics = unsafe { &*(diagnosticContext as *const RefCell<Vec<Diagnostic>>) };
+		
+		let severity = unsafe { LLVMGetDiagInfoSeverity(diagnosticInfo) };
+		let description = unsafe { LLVMGetDiagInfoDescription(diagnosticInfo) };
+		let message = if description.is_null()
+		{
+			"(unknown)".to_owned()
+		}
+		else
+		{
+			let message = (unsafe { CStr::from_ptr(description) }).to_string_lossy().into_owned();
+			unsafe { LLVMDisposeMessage(description) };
+			message
+		};
+		
+		// Must not panic across the FFI boundary
+		if let Ok(mut diagnostics) = diagnostics.try_borrow_mut()
+		{
+			diagnostics.push(Diagnostic::new(severity, message));
+		}
 	}
 }
@@ -0,0 +1,63 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic
+{
+	pub severity: LLVMDiagnosticSeverity,
+	pub message: String,
+	
+	/// Only present if LLVM's message is of the form `<buffer name>:LINE:COLUMN: ...`, as the IR parser's are
+	pub line: Option<u32>,
+	pub column: Option<u32>,
+}
+
+impl Display for Diagnostic
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use ::llvm_sys::LLVMDiagnosticSeverity::*;
+		
+		let severity = match self.severity
+		{
+			LLVMDSError => "error",
+			LLVMDSWarning => "warning",
+			LLVMDSRemark => "remark",
+			LLVMDSNote => "note",
+		};
+		
+		write!(f, "{}: {}", severity, self.message)
+	}
+}
+
+impl Diagnostic
+{
+	#[inline(always)]
+	pub(crate) fn new(severity: LLVMDiagnosticSeverity, message: String) -> Self
+	{
+		let (line, column) =
+		{
+			let mut numbers = message.split(':').skip(1).take(2).map(|part| part.trim().parse::<u32>().ok());
+			match (numbers.next(), numbers.next())
+			{
+				(Some(Some(line)), Some(Some(column))) => (Some(line), Some(column)),
+				_ => (None, None),
+			}
+		};
+		
+		Self
+		{
+			severity: severity,
+			message: message,
+			line: line,
+			column: column,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn isError(&self) -> bool
+	{
+		self.severity == LLVMDiagnosticSeverity::LLVMDSError
+	}
+}
@@ -0,0 +1,57 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A tagged argument to (or result from) a function whose signature is only known at runtime
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum DynamicValue
+{
+	Int1(bool),
+	Int8(u8),
+	Int16(u16),
+	Int32(u32),
+	Int64(u64),
+	Float32(f32),
+	Float64(f64),
+	Pointer(*mut c_void),
+}
+
+impl DynamicValue
+{
+	#[inline(always)]
+	pub fn kind(&self) -> DynamicValueKind
+	{
+		use self::DynamicValue::*;
+		
+		match *self
+		{
+			Int1(_) => DynamicValueKind::Int1,
+			Int8(_) => DynamicValueKind::Int8,
+			Int16(_) => DynamicValueKind::Int16,
+			Int32(_) => DynamicValueKind::Int32,
+			Int64(_) => DynamicValueKind::Int64,
+			Float32(_) => DynamicValueKind::Float32,
+			Float64(_) => DynamicValueKind::Float64,
+			Pointer(_) => DynamicValueKind::Pointer,
+		}
+	}
+	
+	/// Values are passed to and from trampolines as 64-bit slots; integers are zero-extended, floats are passed as their bits
+	#[inline(always)]
+	pub(crate) fn toSlot(&self) -> u64
+	{
+		use self::DynamicValue::*;
+		
+		match *self
+		{
+			Int1(value) => value as u64,
+			Int8(value) => value as u64,
+			Int16(value) => value as u64,
+			Int32(value) => value as u64,
+			Int64(value) => value,
+			Float32(value) => value.to_bits() as u64,
+			Float64(value) => value.to_bits(),
+			Pointer(value) => value as usize as u64,
+		}
+	}
+}
@@ -0,0 +1,67 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DynamicValueKind
+{
+	/// Only valid as a return kind
+	Void,
+	
+	Int1,
+	Int8,
+	Int16,
+	Int32,
+	Int64,
+	Float32,
+	Float64,
+	Pointer,
+}
+
+impl DynamicValueKind
+{
+	/// None if the type can not be passed dynamically (eg structs passed by value)
+	#[inline(always)]
+	pub fn fromLLVMTypeRef(typeRef: LLVMTypeRefWrapper) -> Option<Self>
+	{
+		use self::DynamicValueKind::*;
+		use self::LLVMTypeKind::*;
+		
+		match typeRef.typeKind()
+		{
+			LLVMVoidTypeKind => Some(Void),
+			LLVMIntegerTypeKind => match typeRef.integerWidth()
+			{
+				1 => Some(Int1),
+				8 => Some(Int8),
+				16 => Some(Int16),
+				32 => Some(Int32),
+				64 => Some(Int64),
+				_ => None,
+			},
+			LLVMFloatTypeKind => Some(Float32),
+			LLVMDoubleTypeKind => Some(Float64),
+			LLVMPointerTypeKind => Some(Pointer),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn fromSlot(&self, slot: u64) -> Option<DynamicValue>
+	{
+		use self::DynamicValueKind::*;
+		
+		match *self
+		{
+			Void => None,
+			Int1 => Some(DynamicValue::Int1(slot != 0)),
+			Int8 => Some(DynamicValue::Int8(slot as u8)),
+			Int16 => Some(DynamicValue::Int16(slot as u16)),
+			Int32 => Some(DynamicValue::Int32(slot as u32)),
+			Int64 => Some(DynamicValue::Int64(slot)),
+			Float32 => Some(DynamicValue::Float32(f32::from_bits(slot as u32))),
+			Float64 => Some(DynamicValue::Float64(f64::from_bits(slot))),
+			Pointer => Some(DynamicValue::Pointer(slot as usize as *mut c_void)),
+		}
+	}
+}
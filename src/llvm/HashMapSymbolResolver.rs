@@ -0,0 +1,56 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Resolves symbols from a table of explicitly exported host functions and statics
+/// Names are as they appear in the plugin's IR, eg `memcpy`
+#[derive(Debug, Default, Clone)]
+pub struct HashMapSymbolResolver
+{
+	symbols: HashMap<CString, u64>,
+}
+
+impl SymbolResolver for HashMapSymbolResolver
+{
+	#[inline(always)]
+	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64
+	{
+		match self.symbols.get(unmangledSymbolName(symbolName))
+		{
+			None => SymbolNotFound,
+			Some(address) => *address,
+		}
+	}
+}
+
+impl HashMapSymbolResolver
+{
+	#[inline(always)]
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+	
+	/// eg `resolver.exportFunction::<unsafe extern "C" fn(u32) -> u32>("host_callback", host_callback)`
+	#[inline(always)]
+	pub fn exportFunction<F: ExternCFunctionPointer>(&mut self, symbolName: &str, function: F) -> &mut Self
+	{
+		self.exportAddress(symbolName, function.toAddress())
+	}
+	
+	/// The static must outlive any plugin that uses it
+	#[inline(always)]
+	pub fn exportStatic<T>(&mut self, symbolName: &str, value: &'static T) -> &mut Self
+	{
+		self.exportAddress(symbolName, value as *const T as usize as u64)
+	}
+	
+	#[inline(always)]
+	pub fn exportAddress(&mut self, symbolName: &str, address: u64) -> &mut Self
+	{
+		debug_assert!(address != SymbolNotFound, "address can not be 0");
+		
+		self.symbols.insert(CString::new(symbolName).expect("Contains embedded NULs"), address);
+		self
+	}
+}
@@ -7,13 +7,15 @@ pub struct JitContext<SR: SymbolResolver>
 	reference: LLVMOrcJITStackRef,
 	dropWrapper: Rc<OrcJitStackDropWrapper>,
 	symbolResolver: SR,
+	targetConfiguration: TargetConfiguration,
 }
 
 impl<SR: SymbolResolver> JitContext<SR>
 {
-	pub fn new(symbolResolver: SR, optimisationLevel: LLVMCodeGenOptLevel) -> Result<Self, String>
+	pub fn new(symbolResolver: SR, targetConfiguration: &TargetConfiguration) -> Result<Self, LlvmError>
 	{
-		let reference = Target::createHostOrcJitStack(optimisationLevel)?;
+		let reference = targetConfiguration.createOrcJitStack()?;
+		symbolResolver.attachedToOrcJitStack(reference);
 		
 		Ok
 		(
@@ -22,26 +24,104 @@ impl<SR: SymbolResolver> JitContext<SR>
 				reference: reference,
 				dropWrapper: Rc::new(OrcJitStackDropWrapper(reference)),
 				symbolResolver: symbolResolver,
+				targetConfiguration: targetConfiguration.clone(),
 			}
 		)
 	}
 	
-	pub fn loadPlugins(&self, moduleSourceCodeType: ModuleSourceCodeType, memoryBufferCreator: &MemoryBufferCreator, context: &Context) -> Result<ModuleInOrcJitStack, String>
+	/// The configuration the ORC JIT stack generates code with
+	#[inline(always)]
+	pub fn targetConfiguration(&self) -> &TargetConfiguration
+	{
+		&self.targetConfiguration
+	}
+	
+	/// Plugins must have a valid manifest (see `PluginManifest`)
+	/// If `optimisation` is given, the plugin is optimised after its manifest is validated and before its imports are checked
+	pub fn loadPlugins(&self, moduleSourceCodeType: ModuleSourceCodeType, memoryBufferCreator: &MemoryBufferCreator, context: &Context, compilationMode: CompilationMode, optimisation: Option<&PassManagerBuilder>) -> Result<ModuleInOrcJitStack, LlvmError>
 	{
 		let module = moduleSourceCodeType.createVerifiedModule(context, memoryBufferCreator)?;
 		
-		Ok(self.loadPluginFromModule(&module))
+		let manifest = PluginManifest::readFromModule(&module)?;
+		manifest.validate(&module)?;
+		
+		if let Some(passManagerBuilder) = optimisation
+		{
+			passManagerBuilder.optimise(&module)?;
+		}
+		
+		rejectForbiddenImports(&self.symbolResolver, &module.undefinedImports()?)?;
+		
+		let unresolvedImports = self.unresolvedImports(&module)?;
+		if unlikely(!unresolvedImports.is_empty())
+		{
+			return Err(LlvmError::UnresolvedImports(unresolvedImports));
+		}
+		rejectForbiddenSymbols(&self.symbolResolver)?;
+		
+		let mut plugins = self.loadPluginFromModule(&module, compilationMode);
+		plugins.manifest = Some(manifest);
+		
+		plugins.finalizeExports();
+		rejectForbiddenSymbols(&self.symbolResolver)?;
+		
+		Ok(plugins)
+	}
+	
+	/// As `loadPlugins()`, but the plugin's code is loaded from `objectCache` if it has previously been compiled with the same bit code and settings, and is otherwise compiled eagerly, with this context's target configuration, and stored there
+	/// The bit code is still parsed, so that the manifest, imports and function signatures can be checked
+	pub fn loadPluginsWithObjectCache(&self, objectCache: &ObjectCache, bitCode: &[u8], context: &Context, optimisation: Option<&PassManagerBuilder>) -> Result<ModuleInOrcJitStack, LlvmError>
+	{
+		let module = ModuleSourceCodeType::BitCode.createVerifiedModule(context, &MemoryBufferCreator::Buffer(bitCode))?;
+		
+		let manifest = PluginManifest::readFromModule(&module)?;
+		manifest.validate(&module)?;
+		
+		rejectForbiddenImports(&self.symbolResolver, &module.undefinedImports()?)?;
+		
+		let unresolvedImports = self.unresolvedImports(&module)?;
+		if unlikely(!unresolvedImports.is_empty())
+		{
+			return Err(LlvmError::UnresolvedImports(unresolvedImports));
+		}
+		rejectForbiddenSymbols(&self.symbolResolver)?;
+		
+		let key = objectCache.key(bitCode, &self.targetConfiguration, optimisation);
+		let objectFile = match objectCache.get(key)?
+		{
+			Some(objectFile) => objectFile,
+			None => objectCache.compileAndPut(key, &module, &self.targetConfiguration, optimisation)?,
+		};
+		
+		let mut plugins = self.loadPluginFromObjectFile(&objectFile);
+		plugins.module = Some(module);
+		plugins.manifest = Some(manifest);
+		
+		plugins.finalizeExports();
+		rejectForbiddenSymbols(&self.symbolResolver)?;
+		
+		Ok(plugins)
+	}
+	
+	/// Checks every import of `module` against this context's symbol resolver (and against plugins already loaded) before it is loaded, so that all missing symbols can be reported at once
+	pub fn unresolvedImports(&self, module: &Module) -> Result<Vec<ModuleImport>, LlvmError>
+	{
+		let imports = module.undefinedImports()?;
+		Ok(imports.into_iter().filter(|import| !import.isResolvable(self.reference, &self.symbolResolver)).collect())
 	}
 	
 	#[inline]
-	pub fn loadPluginFromModule(&self, module: &Module) -> ModuleInOrcJitStack
+	pub fn loadPluginFromModule(&self, module: &Module, compilationMode: CompilationMode) -> ModuleInOrcJitStack
 	{
-		let reference = unsafe { LLVMOrcAddEagerlyCompiledIR(self.reference, module.reference, Self::resolveSymbol, self.symbolResolver()) };
+		let reference = compilationMode.addToOrcJitStack(self.reference, module.reference, Self::resolveSymbol, self.symbolResolver());
 		ModuleInOrcJitStack
 		{
 			reference: reference,
 			orcJitStackReference: self.reference,
 			orcJitStackReferenceDropWrapper: self.dropWrapper.clone(),
+			module: Some(module.clone()),
+			manifest: None,
+			dynamicFunctionTrampolines: RefCell::new(HashMap::new()),
 		}
 	}
 	
@@ -55,6 +135,9 @@ impl<SR: SymbolResolver> JitContext<SR>
 			reference: reference,
 			orcJitStackReference: self.reference,
 			orcJitStackReferenceDropWrapper: self.dropWrapper.clone(),
+			module: None,
+			manifest: None,
+			dynamicFunctionTrampolines: RefCell::new(HashMap::new()),
 		}
 	}
 	
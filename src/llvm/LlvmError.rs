@@ -0,0 +1,290 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub enum LlvmError
+{
+	/// An LLVM function failed; `message` is LLVM's error message, or `(unknown)` if it did not give one
+	LlvmFunctionFailed { functionName: &'static str, message: String },
+	
+	/// eg a context or module
+	CouldNotCreate(&'static str),
+	
+	Io(io::Error),
+	
+	/// `message` is LLVM's error message
+	FileIo { filePath: String, message: String },
+	
+	/// `diagnostics` are those LLVM reported while parsing
+	BitCodeParse { diagnostics: Vec<Diagnostic> },
+	
+	/// `line` and `column` are taken from LLVM's error message, if present; `diagnostics` ends with the parser's error
+	IntermediateRepresentationParse { message: String, line: Option<u32>, column: Option<u32>, diagnostics: Vec<Diagnostic> },
+	
+	/// `diagnostics` are those LLVM reported for the module's context since they were last taken, eg whilst parsing
+	Verification { message: String, diagnostics: Vec<Diagnostic> },
+	
+	/// eg there is no target for the host triple or a target machine or ORC JIT stack could not be created for it
+	TargetInitialisation { message: String },
+	
+	InvalidObjectFile,
+	
+	/// Missing or malformed
+	InvalidPluginManifest { message: String },
+	
+	IncompatibleHostAbiVersion { pluginName: String, pluginVersion: String, requiredHostAbiVersion: u32 },
+	
+	UnresolvedImports(Vec<ModuleImport>),
+	
+	/// Mangled names
+	ForbiddenSymbols(Vec<CString>),
+	
+	FunctionNotFound { functionName: String },
+	
+	/// Declared but not defined
+	FunctionNotDefined { functionName: String },
+	
+	FunctionHasNoAddress { functionName: String },
+	
+	FunctionSignatureMismatch { functionName: String, expected: String, actual: String },
+	
+	/// The plugin was loaded from an object file, so there is no IR to check (or generate code) against
+	NoIntermediateRepresentation { functionName: String },
+	
+	/// eg a metadata type, or a type that can not be used with a `DynamicValue`
+	UnsupportedType { description: String },
+	
+	/// The wrong number or kind of arguments were passed
+	DynamicArguments { message: String },
+	
+	IndirectStub { stubName: String, message: String },
+	
+	/// `diagnostics` are those LLVM reported whilst linking
+	ModuleLinking { message: String, diagnostics: Vec<Diagnostic> },
+	
+	DifferentJitContext,
+	
+	/// eg a plugin was added to a `LoadedModulesSymbolResolver` that belongs to the plugin's own JIT context
+	SameJitContext,
+}
+
+impl Display for LlvmError
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::LlvmError::*;
+		
+		match *self
+		{
+			LlvmFunctionFailed { functionName, ref message } => write!(f, "{}:{}", functionName, message),
+			CouldNotCreate(what) => write!(f, "Could not create {}", what),
+			Io(ref error) => write!(f, "I/O error '{}'", error),
+			FileIo { ref filePath, ref message } => write!(f, "Could not read file '{}' because '{}'", filePath, message),
+			BitCodeParse { ref diagnostics } =>
+			{
+				write!(f, "Could not parse bit code into module")?;
+				for diagnostic in diagnostics.iter().filter(|diagnostic| diagnostic.isError())
+				{
+					write!(f, "; {}", diagnostic.message)?;
+				}
+				Ok(())
+			}
+			IntermediateRepresentationParse { ref message, .. } => write!(f, "Could not parse intermediate representation into module because '{}'", message),
+			Verification { ref message, .. } => write!(f, "Module failed verification because '{}'", message),
+			TargetInitialisation { ref message } => write!(f, "Could not initialise target because '{}'", message),
+			InvalidObjectFile => write!(f, "Not a valid object file"),
+			InvalidPluginManifest { ref message } => write!(f, "{}", message),
+			IncompatibleHostAbiVersion { ref pluginName, ref pluginVersion, requiredHostAbiVersion } => write!(f, "Plugin '{}' version '{}' requires host ABI version {} but this host is version {}", pluginName, pluginVersion, requiredHostAbiVersion, PluginManifest::HostAbiVersion),
+			UnresolvedImports(ref unresolvedImports) =>
+			{
+				write!(f, "Plugin has unresolved imports: ")?;
+				for (index, import) in unresolvedImports.iter().enumerate()
+				{
+					if index != 0
+					{
+						write!(f, ", ")?;
+					}
+					let kind = match import.kind
+					{
+						ModuleImportKind::Function => "function",
+						ModuleImportKind::GlobalVariable => "global variable",
+					};
+					write!(f, "{} '{}'", kind, import.name.to_string_lossy())?;
+				}
+				Ok(())
+			}
+			ForbiddenSymbols(ref symbolNames) =>
+			{
+				let names: Vec<Cow<str>> = symbolNames.iter().map(|symbolName| symbolName.to_string_lossy()).collect();
+				write!(f, "Plugin references forbidden symbols: {}", names.join(", "))
+			}
+			FunctionNotFound { ref functionName } => write!(f, "Function '{}' is not present in the plugin", functionName),
+			FunctionNotDefined { ref functionName } => write!(f, "Function '{}' is only declared, not defined, in the plugin", functionName),
+			FunctionHasNoAddress { ref functionName } => write!(f, "Function '{}' is declared in the plugin but has no address", functionName),
+			FunctionSignatureMismatch { ref functionName, ref expected, ref actual } => write!(f, "Function '{}' has signature '{}' in the plugin but was expected to be '{}'", functionName, actual, expected),
+			NoIntermediateRepresentation { ref functionName } => write!(f, "Function '{}' can not be checked as the plugin was loaded from an object file", functionName),
+			UnsupportedType { ref description } => write!(f, "Unsupported type: {}", description),
+			DynamicArguments { ref message } => write!(f, "{}", message),
+			IndirectStub { ref stubName, ref message } => write!(f, "Indirect stub '{}' failed because '{}'", stubName, message),
+			ModuleLinking { ref message, ref diagnostics } =>
+			{
+				write!(f, "Could not link modules because '{}'", message)?;
+				for diagnostic in diagnostics.iter().filter(|diagnostic| diagnostic.isError())
+				{
+					write!(f, "; {}", diagnostic.message)?;
+				}
+				Ok(())
+			}
+			DifferentJitContext => write!(f, "Plugin was loaded into a different JIT context"),
+			SameJitContext => write!(f, "Plugin was loaded into the JIT context the symbol resolver belongs to"),
+		}
+	}
+}
+
+impl Error for LlvmError
+{
+	fn description(&self) -> &str
+	{
+		use self::LlvmError::*;
+		
+		match *self
+		{
+			LlvmFunctionFailed { .. } => "LLVM function failed",
+			CouldNotCreate(_) => "could not create",
+			Io(ref error) => error.description(),
+			FileIo { .. } => "could not read file",
+			BitCodeParse { .. } => "could not parse bit code",
+			IntermediateRepresentationParse { .. } => "could not parse intermediate representation",
+			Verification { .. } => "module failed verification",
+			TargetInitialisation { .. } => "could not initialise target",
+			InvalidObjectFile => "not a valid object file",
+			InvalidPluginManifest { .. } => "missing or malformed plugin manifest",
+			IncompatibleHostAbiVersion { .. } => "plugin requires a different host ABI version",
+			UnresolvedImports(_) => "plugin has unresolved imports",
+			ForbiddenSymbols(_) => "plugin references forbidden symbols",
+			FunctionNotFound { .. } => "function not found",
+			FunctionNotDefined { .. } => "function is only declared",
+			FunctionHasNoAddress { .. } => "function has no address",
+			FunctionSignatureMismatch { .. } => "function signature mismatch",
+			NoIntermediateRepresentation { .. } => "plugin has no intermediate representation",
+			UnsupportedType { .. } => "unsupported type",
+			DynamicArguments { .. } => "wrong arguments",
+			IndirectStub { .. } => "indirect stub failed",
+			ModuleLinking { .. } => "could not link modules",
+			DifferentJitContext => "different JIT context",
+			SameJitContext => "same JIT context",
+		}
+	}
+	
+	fn cause(&self) -> Option<&Error>
+	{
+		match *self
+		{
+			LlvmError::Io(ref error) => Some(error),
+			_ => None,
+		}
+	}
+}
+
+impl From<io::Error> for LlvmError
+{
+	#[inline(always)]
+	fn from(error: io::Error) -> Self
+	{
+		LlvmError::Io(error)
+	}
+}
+
+impl LlvmError
+{
+	/// Diagnostics attached to this error, if any; empty for most errors
+	#[inline(always)]
+	pub fn diagnostics(&self) -> &[Diagnostic]
+	{
+		use self::LlvmError::*;
+		
+		match *self
+		{
+			BitCodeParse { ref diagnostics } => diagnostics,
+			IntermediateRepresentationParse { ref diagnostics, .. } => diagnostics,
+			Verification { ref diagnostics, .. } => diagnostics,
+			ModuleLinking { ref diagnostics, .. } => diagnostics,
+			_ => &[],
+		}
+	}
+	
+	/// LLVM's messages are of the form `<buffer name>:LINE:COLUMN: error: ...`; the IR parser does not report its error through the diagnostic handler, so it is appended to `diagnostics`
+	pub(crate) fn intermediateRepresentationParse(message: String, mut diagnostics: Vec<Diagnostic>) -> Self
+	{
+		let diagnostic = Diagnostic::new(LLVMDiagnosticSeverity::LLVMDSError, message.clone());
+		let line = diagnostic.line;
+		let column = diagnostic.column;
+		diagnostics.push(diagnostic);
+		
+		LlvmError::IntermediateRepresentationParse
+		{
+			message: message,
+			line: line,
+			column: column,
+			diagnostics: diagnostics,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn functionSignatureMismatch(functionName: &str, expected: &LlvmType, actual: LLVMTypeRefWrapper) -> Self
+	{
+		LlvmError::FunctionSignatureMismatch
+		{
+			functionName: functionName.to_owned(),
+			expected: expected.to_string(),
+			actual: actual.toString().to_string_lossy().into_owned(),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn moduleLinking<S: Into<String>>(message: S, diagnostics: Vec<Diagnostic>) -> Self
+	{
+		LlvmError::ModuleLinking
+		{
+			message: message.into(),
+			diagnostics: diagnostics,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn invalidPluginManifest<S: Into<String>>(message: S) -> Self
+	{
+		LlvmError::InvalidPluginManifest
+		{
+			message: message.into(),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn dynamicArguments<S: Into<String>>(message: S) -> Self
+	{
+		LlvmError::DynamicArguments
+		{
+			message: message.into(),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn unsupportedType<S: Into<String>>(description: S) -> Self
+	{
+		LlvmError::UnsupportedType
+		{
+			description: description.into(),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn targetInitialisation<S: Into<String>>(message: S) -> Self
+	{
+		LlvmError::TargetInitialisation
+		{
+			message: message.into(),
+		}
+	}
+}
@@ -0,0 +1,89 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Resolves symbols from plugins already loaded into other `JitContext`s, so that plugins can call each other
+/// Only the functions exported in each plugin's manifest are resolved, not anything else in its ORC JIT stack
+/// Plugins loaded into the same `JitContext` can already resolve each other's symbols without this, and can not be added to it
+/// Wrap in a `Rc` to add plugins after passing it to a `JitContext`
+#[derive(Default)]
+pub struct LoadedModulesSymbolResolver
+{
+	modules: RefCell<Vec<Rc<ModuleInOrcJitStack>>>,
+	ownOrcJitStackReferences: RefCell<Vec<LLVMOrcJITStackRef>>,
+}
+
+impl SymbolResolver for LoadedModulesSymbolResolver
+{
+	#[inline(always)]
+	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64
+	{
+		let symbolName = unmangledSymbolName(symbolName);
+		let ownOrcJitStackReferences = self.ownOrcJitStackReferences.borrow();
+		
+		for module in self.modules.borrow().iter()
+		{
+			// Looking up a symbol in the ORC JIT stack that is asking would re-enter it
+			if ownOrcJitStackReferences.contains(&module.orcJitStackReference)
+			{
+				continue;
+			}
+			
+			if !Self::isExported(module, symbolName)
+			{
+				continue;
+			}
+			
+			let address = module.symbolAddress(symbolName);
+			if address != SymbolNotFound
+			{
+				return address;
+			}
+		}
+		SymbolNotFound
+	}
+	
+	#[inline(always)]
+	fn attachedToOrcJitStack(&self, orcJitStackReference: LLVMOrcJITStackRef)
+	{
+		self.ownOrcJitStackReferences.borrow_mut().push(orcJitStackReference)
+	}
+}
+
+impl LoadedModulesSymbolResolver
+{
+	#[inline(always)]
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+	
+	/// Plugins are searched in the order they were added
+	/// Fails if `module` has no manifest, or was loaded into a `JitContext` that this resolver has been given to
+	#[inline(always)]
+	pub fn add(&self, module: Rc<ModuleInOrcJitStack>) -> Result<(), LlvmError>
+	{
+		if unlikely(module.manifest.is_none())
+		{
+			return Err(LlvmError::InvalidPluginManifest { message: "Only plugins loaded with a manifest export functions to a LoadedModulesSymbolResolver".to_owned() });
+		}
+		
+		if unlikely(self.ownOrcJitStackReferences.borrow().contains(&module.orcJitStackReference))
+		{
+			return Err(LlvmError::SameJitContext);
+		}
+		
+		self.modules.borrow_mut().push(module);
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn isExported(module: &ModuleInOrcJitStack, symbolName: &CStr) -> bool
+	{
+		match module.manifest
+		{
+			None => false,
+			Some(ref manifest) => manifest.exports.iter().any(|export| export.functionName.as_bytes() == symbolName.to_bytes()),
+		}
+	}
+}
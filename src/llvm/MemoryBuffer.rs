@@ -56,14 +56,20 @@ impl<'a> MemoryBuffer<'a>
 	}
 	
 	#[inline(always)]
-	pub fn fromFile(filePath: &str) -> Result<Self, String>
+	pub fn fromFile<P: AsRef<Path>>(filePath: P) -> Result<Self, LlvmError>
 	{
-		let filePath = CString::new(filePath).expect("File path contains embedded NULs");
+		let filePath = filePath.as_ref();
+		
+		let filePathCString = match CString::new(filePath.as_os_str().as_bytes())
+		{
+			Err(_) => return Err(LlvmError::FileIo { filePath: filePath.to_string_lossy().into_owned(), message: "File path contains embedded NULs".to_owned() }),
+			Ok(filePathCString) => filePathCString,
+		};
 		
 		let mut reference = unsafe { uninitialized() };
 		let mut errorMessage = null_mut();
-		let boolean = unsafe { LLVMCreateMemoryBufferWithContentsOfFile(filePath.as_ptr(), &mut reference, &mut errorMessage) };
-		handle_boolean_and_error_message!(boolean, errorMessage, LLVMCreateMemoryBufferWithContentsOfFile);
+		let boolean = unsafe { LLVMCreateMemoryBufferWithContentsOfFile(filePathCString.as_ptr(), &mut reference, &mut errorMessage) };
+		handle_boolean_and_error_message!(boolean, errorMessage, LLVMCreateMemoryBufferWithContentsOfFile, |message| LlvmError::FileIo { filePath: filePath.to_string_lossy().into_owned(), message: message });
 		
 		Ok
 		(
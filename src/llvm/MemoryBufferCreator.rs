@@ -7,15 +7,14 @@ pub enum MemoryBufferCreator<'a>
 {
 	Buffer(&'a [u8]),
 	
-	/// We could use Path, but it is such a pain to get a *const c_char null terminated string from...
-	File(&'a str),
+	File(&'a Path),
 }
 
 impl<'a> MemoryBufferCreator<'a>
 {
 	// Potentially could be replaced by an implementation of the From trait
 	#[inline(always)]
-	pub fn createMemoryBuffer(&self) -> Result<MemoryBuffer<'a>, String>
+	pub fn createMemoryBuffer(&self) -> Result<MemoryBuffer<'a>, LlvmError>
 	{
 		use self::MemoryBufferCreator::*;
 		
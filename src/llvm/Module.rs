@@ -13,12 +13,12 @@ pub struct Module
 impl Module
 {
 	#[inline(always)]
-	pub fn useAsTemplateForNewModule(&self) -> Result<Self, String>
+	pub fn useAsTemplateForNewModule(&self) -> Result<Self, LlvmError>
 	{
 		let reference = unsafe { LLVMCloneModule(self.reference) };
 		if unlikely(reference.is_null())
 		{
-			Err("Could not clone".to_owned())
+			Err(LlvmError::CouldNotCreate("clone of module"))
 		}
 		else
 		{
@@ -34,23 +34,58 @@ impl Module
 		}
 	}
 	
+	/// Creates an empty module in the same context and with the same target triple and data layout as this one
+	pub(crate) fn createSiblingModule(&self, name: &str) -> Result<Self, LlvmError>
+	{
+		let cName = CString::new(name).expect("name contains embedded NULs");
+		let reference = unsafe { LLVMModuleCreateWithNameInContext(cName.as_ptr(), LLVMGetModuleContext(self.reference)) };
+		if unlikely(reference.is_null())
+		{
+			Err(LlvmError::CouldNotCreate("module"))
+		}
+		else
+		{
+			unsafe { LLVMSetTarget(reference, LLVMGetTarget(self.reference)) };
+			unsafe { LLVMSetDataLayout(reference, LLVMGetDataLayoutStr(self.reference)) };
+			
+			Ok
+			(
+				Self
+				{
+					reference: reference,
+					dropWrapper: Rc::new(ModuleDropWrapper(reference)),
+					parentDropWrapper: self.parentDropWrapper.clone(),
+				}
+			)
+		}
+	}
+	
 	#[inline(always)]
-	pub fn verify(self) -> Result<Self, String>
+	pub fn verify(self) -> Result<Self, LlvmError>
 	{
 		self.verifyReference().map(|_| self)
 	}
 	
 	#[inline(always)]
-	pub fn verifyReference(&self) -> Result<(), String>
+	pub fn verifyReference(&self) -> Result<(), LlvmError>
 	{
+		self.parentDropWrapper.clearDiagnostics();
+		
 		let mut errorMessage = null_mut();
 		let boolean = unsafe { LLVMVerifyModule(self.reference, LLVMVerifierFailureAction::LLVMReturnStatusAction, &mut errorMessage) };
-		handle_boolean_and_error_message!(boolean, errorMessage, LLVMVerifyModule);
+		handle_boolean_and_error_message!(boolean, errorMessage, LLVMVerifyModule, |message| LlvmError::Verification { message: message, diagnostics: self.takeDiagnostics() });
 		Ok(())
 	}
 	
+	/// Removes and returns the diagnostics LLVM has reported for this module's context since they were last taken, or since the last parse, verification or link began
 	#[inline(always)]
-	pub fn executionEngineMachineCodeJit(&self) -> Result<ExecutionEngine, String>
+	pub fn takeDiagnostics(&self) -> Vec<Diagnostic>
+	{
+		self.parentDropWrapper.takeDiagnostics()
+	}
+	
+	#[inline(always)]
+	pub fn executionEngineMachineCodeJit(&self) -> Result<ExecutionEngine, LlvmError>
 	{
 		let sizeOfOptions = size_of::<LLVMMCJITCompilerOptions>();
 		
@@ -96,6 +131,162 @@ impl Module
 		globalFieldDefinition.create(context, self)
 	}
 	
+	/// `None` if `name` contains a NUL, as no function can have such a name
+	#[inline(always)]
+	pub fn namedFunction(&self, name: &str) -> Option<FunctionValue>
+	{
+		let cName = match CString::new(name)
+		{
+			Err(_) => return None,
+			Ok(cName) => cName,
+		};
+		let reference = unsafe { LLVMGetNamedFunction(self.reference, cName.as_ptr()) };
+		if unlikely(reference.is_null())
+		{
+			None
+		}
+		else
+		{
+			Some(FunctionValue::fromLLVMValueRef(reference))
+		}
+	}
+	
+	/// Includes declarations and intrinsics
+	#[inline(always)]
+	pub fn functions(&self) -> ModuleFunctions
+	{
+		ModuleFunctions::new(self)
+	}
+	
+	/// Includes declarations
+	#[inline(always)]
+	pub fn globals(&self) -> ModuleGlobals
+	{
+		ModuleGlobals::new(self)
+	}
+	
+	/// Functions (other than intrinsics) and global variables that are declared but not defined, ie must be resolved when the module is loaded
+	pub fn undefinedImports(&self) -> Result<Vec<ModuleImport>, LlvmError>
+	{
+		let mut imports = Vec::new();
+		
+		for functionValue in self.functions()
+		{
+			if functionValue.isDeclaration() && !functionValue.isIntrinsic()
+			{
+				imports.push(ModuleImport::new(functionValue.asLLVMValueRef(), ModuleImportKind::Function, functionValue.functionType(), functionValue.linkage())?);
+			}
+		}
+		
+		for globalValue in self.globals()
+		{
+			if globalValue.isDeclaration()
+			{
+				imports.push(ModuleImport::new(globalValue.asLLVMValueRef(), ModuleImportKind::GlobalVariable, globalValue.valueType(), globalValue.linkage())?);
+			}
+		}
+		
+		Ok(imports)
+	}
+	
+	/// Links `other` into this module, destroying `other`; both must be in the same context, and `other` must not have been cloned (or loaded into a JIT)
+	pub fn linkIn(&self, other: Module, internalisation: ModuleInternalisation) -> Result<(), LlvmError>
+	{
+		use self::ModuleInternalisation::*;
+		
+		if unlikely(unsafe { LLVMGetModuleContext(self.reference) } != unsafe { LLVMGetModuleContext(other.reference) })
+		{
+			return Err(LlvmError::moduleLinking("Modules are in different contexts", Vec::new()));
+		}
+		
+		let linkedInDefinitionNames = match internalisation
+		{
+			LinkedIn => other.definitionNames(),
+			_ => HashSet::new(),
+		};
+		
+		// LLVMLinkModules2 destroys the module linked in, even if it fails
+		let otherReference = other.reference;
+		match Rc::try_unwrap(other.dropWrapper)
+		{
+			Err(_) => return Err(LlvmError::moduleLinking("Module to link in is still in use", Vec::new())),
+			Ok(dropWrapper) => forget(dropWrapper),
+		}
+		
+		self.parentDropWrapper.clearDiagnostics();
+		let boolean = unsafe { LLVMLinkModules2(self.reference, otherReference) };
+		if unlikely(boolean != 0)
+		{
+			return Err(LlvmError::moduleLinking("LLVMLinkModules2 failed", self.takeDiagnostics()));
+		}
+		
+		match internalisation
+		{
+			None => (),
+			LinkedIn => self.internalise(|name| linkedInDefinitionNames.contains(name)),
+			AllExcept(ref names) => self.internalise(|name| !names.iter().any(|exportedName| exportedName.as_bytes() == name.to_bytes())),
+		}
+		
+		Ok(())
+	}
+	
+	/// Gives internal linkage to every externally visible function and global variable definition for which `shouldInternalise` is true; LLVM's own globals (eg `llvm.used`) are left alone
+	pub fn internalise<P: Fn(&CStr) -> bool>(&self, shouldInternalise: P)
+	{
+		let internaliseIfAllowed = |reference: LLVMValueRef|
+		{
+			use ::llvm_sys::LLVMLinkage::*;
+			
+			if unsafe { LLVMIsDeclaration(reference) } != 0
+			{
+				return;
+			}
+			
+			match unsafe { LLVMGetLinkage(reference) }
+			{
+				LLVMExternalLinkage | LLVMLinkOnceAnyLinkage | LLVMLinkOnceODRLinkage | LLVMWeakAnyLinkage | LLVMWeakODRLinkage | LLVMCommonLinkage => (),
+				_ => return,
+			}
+			
+			let name = unsafe { CStr::from_ptr(LLVMGetValueName(reference)) };
+			if name.to_bytes().starts_with(b"llvm.") || !shouldInternalise(name)
+			{
+				return;
+			}
+			
+			unsafe { LLVMSetLinkage(reference, LLVMInternalLinkage) };
+			unsafe { LLVMSetVisibility(reference, LLVMVisibility::LLVMDefaultVisibility) };
+			unsafe { LLVMSetDLLStorageClass(reference, LLVMDLLStorageClass::LLVMDefaultStorageClass) };
+		};
+		
+		for functionValue in self.functions()
+		{
+			internaliseIfAllowed(functionValue.asLLVMValueRef());
+		}
+		
+		for globalValue in self.globals()
+		{
+			internaliseIfAllowed(globalValue.asLLVMValueRef());
+		}
+	}
+	
+	fn definitionNames(&self) -> HashSet<CString>
+	{
+		let mut definitionNames = HashSet::new();
+		
+		for functionValue in self.functions().filter(|functionValue| !functionValue.isDeclaration())
+		{
+			definitionNames.insert(functionValue.name());
+		}
+		
+		for globalValue in self.globals().filter(|globalValue| !globalValue.isDeclaration())
+		{
+			definitionNames.insert(globalValue.name());
+		}
+		
+		definitionNames
+	}
+	
 	#[inline(always)]
 	pub fn addMetadata(&self, context: &Context, key: &str, metadata: &MetadataNode)
 	{
@@ -103,6 +294,18 @@ impl Module
 		unsafe { LLVMAddNamedMetadataOperand(self.reference, key.as_ptr(), context.metadataNode(metadata).asLLVMValueRef()) }
 	}
 	
+	/// Empty if there is no named metadata for key
+	#[inline(always)]
+	pub fn namedMetadataOperands(&self, key: &str) -> Vec<LLVMValueRef>
+	{
+		let key = CString::new(key).unwrap();
+		let numberOfOperands = (unsafe { LLVMGetNamedMetadataNumOperands(self.reference, key.as_ptr()) }) as usize;
+		let mut operands = Vec::with_capacity(numberOfOperands);
+		unsafe { LLVMGetNamedMetadataOperands(self.reference, key.as_ptr(), operands.as_mut_ptr()) };
+		unsafe { operands.set_len(numberOfOperands) };
+		operands
+	}
+	
 	#[inline(always)]
 	pub fn dumpToStandardError(&self)
 	{
@@ -110,7 +313,7 @@ impl Module
 	}
 	
 	#[inline(always)]
-	pub fn writeBitCodeToFile(&self, path: &CStr) -> Result<(), String>
+	pub fn writeBitCodeToFile(&self, path: &CStr) -> Result<(), LlvmError>
 	{
 		let result = unsafe { LLVMWriteBitcodeToFile(self.reference, path.as_ptr()) };
 		if result == 0
@@ -119,7 +322,7 @@ impl Module
 		}
 		else
 		{
-			Err(format!("Unknown failure '{:?}' writing bit code to file path {:?}", result, path))
+			Err(LlvmError::FileIo { filePath: path.to_string_lossy().into_owned(), message: format!("Unknown failure '{:?}' writing bit code", result) })
 		}
 	}
 	
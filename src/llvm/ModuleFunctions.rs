@@ -0,0 +1,44 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Iterates over the functions in a module, in the order they were added
+#[derive(Debug, Clone)]
+pub struct ModuleFunctions<'a>
+{
+	next: LLVMValueRef,
+	module: PhantomData<&'a Module>,
+}
+
+impl<'a> Iterator for ModuleFunctions<'a>
+{
+	type Item = FunctionValue;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.next.is_null()
+		{
+			None
+		}
+		else
+		{
+			let current = self.next;
+			self.next = unsafe { LLVMGetNextFunction(current) };
+			Some(FunctionValue::fromLLVMValueRef(current))
+		}
+	}
+}
+
+impl<'a> ModuleFunctions<'a>
+{
+	#[inline(always)]
+	fn new(module: &'a Module) -> Self
+	{
+		Self
+		{
+			next: unsafe { LLVMGetFirstFunction(module.reference) },
+			module: PhantomData,
+		}
+	}
+}
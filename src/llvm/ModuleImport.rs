@@ -0,0 +1,64 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A function or global variable that a module declares but does not define
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleImport
+{
+	/// Not mangled
+	pub name: CString,
+	pub kind: ModuleImportKind,
+	
+	/// A `LlvmType::Function` for functions, otherwise the type of the global variable's value
+	pub llvmType: LlvmType,
+	
+	pub linkage: LLVMLinkage,
+}
+
+impl ModuleImport
+{
+	#[inline(always)]
+	pub(crate) fn new(value: LLVMValueRef, kind: ModuleImportKind, llvmType: LLVMTypeRefWrapper, linkage: LLVMLinkage) -> Result<Self, LlvmError>
+	{
+		Ok
+		(
+			Self
+			{
+				name: (unsafe { CStr::from_ptr(value.getName()) }).to_owned(),
+				kind: kind,
+				llvmType: LlvmType::fromLLVMTypeRef(llvmType)?,
+				linkage: linkage,
+			}
+		)
+	}
+	
+	/// Extern weak imports may legitimately resolve to nothing
+	#[inline(always)]
+	pub fn isOptional(&self) -> bool
+	{
+		self.linkage == LLVMLinkage::LLVMExternalWeakLinkage
+	}
+	
+	/// Resolvable if already defined in the ORC JIT stack (eg by another plugin), or if the symbol resolver can resolve it
+	pub(crate) fn isResolvable<SR: SymbolResolver + ?Sized>(&self, orcJitStackReference: LLVMOrcJITStackRef, symbolResolver: &SR) -> bool
+	{
+		if self.isOptional()
+		{
+			return true;
+		}
+		
+		if unsafe { LLVMOrcGetSymbolAddress(orcJitStackReference, self.name.as_ptr()) } != SymbolNotFound
+		{
+			return true;
+		}
+		
+		// Symbol resolvers are passed mangled names
+		let mut mangledName = null_mut();
+		unsafe { LLVMOrcGetMangledSymbol(orcJitStackReference, &mut mangledName, self.name.as_ptr()) };
+		let address = symbolResolver.resolveSymbolAddress(unsafe { CStr::from_ptr(mangledName) });
+		unsafe { LLVMOrcDisposeMangledSymbol(mangledName) };
+		
+		address != SymbolNotFound
+	}
+}
@@ -0,0 +1,35 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Which definitions `Module::linkIn()` gives internal linkage after linking, so that they can only be used from within the linked module (and can be inlined and removed by optimisation)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModuleInternalisation
+{
+	/// Linkage is unchanged
+	None,
+	
+	/// Definitions from the module linked in, eg a helper library, are made internal; those of the module linked into are unchanged
+	LinkedIn,
+	
+	/// Every definition other than these (eg a plugin's exports) is made internal
+	AllExcept(Vec<String>),
+}
+
+impl Default for ModuleInternalisation
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		ModuleInternalisation::None
+	}
+}
+
+impl ModuleInternalisation
+{
+	#[inline(always)]
+	pub fn allExceptManifestExports(manifest: &PluginManifest) -> Self
+	{
+		ModuleInternalisation::AllExcept(manifest.exports.iter().map(|export| export.functionName.clone()).collect())
+	}
+}
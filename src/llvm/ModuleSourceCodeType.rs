@@ -12,7 +12,7 @@ pub enum ModuleSourceCodeType
 impl ModuleSourceCodeType
 {
 	#[inline(always)]
-	pub fn createVerifiedModule<'a>(&self, context: &Context, memoryBufferCreator: &MemoryBufferCreator<'a>) -> Result<Module, String>
+	pub fn createVerifiedModule<'a>(&self, context: &Context, memoryBufferCreator: &MemoryBufferCreator<'a>) -> Result<Module, LlvmError>
 	{
 		use self::ModuleSourceCodeType::*;
 		
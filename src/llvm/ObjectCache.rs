@@ -0,0 +1,186 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// An on-disk cache of object files compiled from plugin bit code for the host, so that plugins need not be recompiled every time a process starts
+/// Use with `JitContext::loadPluginsWithObjectCache()`, which compiles objects with the `JitContext`'s own target configuration, so that they are the same as the code its ORC JIT stack would generate
+pub struct ObjectCache
+{
+	folderPath: PathBuf,
+	maximumSizeInBytes: u64,
+}
+
+impl ObjectCache
+{
+	/// Increment if the way objects are compiled, or the way the key's inputs are hashed, changes in a way that the key does not capture
+	pub const FormatVersion: u32 = 1;
+	
+	const FolderName: &'static str = "predicator-object-cache";
+	
+	const FileExtension: &'static str = "o";
+	
+	/// Objects are stored in a `predicator-object-cache` folder inside `temporaryFolderPath`; if adding an object takes the cache over `maximumSizeInBytes`, the oldest objects are removed
+	pub fn new(temporaryFolderPath: &TemporaryFolderPath, maximumSizeInBytes: u64) -> Result<Self, LlvmError>
+	{
+		let folderPath = temporaryFolderPath.path()?.join(Self::FolderName);
+		create_dir_all(&folderPath)?;
+		
+		Ok
+		(
+			Self
+			{
+				folderPath: folderPath,
+				maximumSizeInBytes: maximumSizeInBytes,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	pub fn folderPath(&self) -> &Path
+	{
+		&self.folderPath
+	}
+	
+	/// A SHA-256 digest of the bit code, this crate's version, the target configuration (target triple, CPU name, CPU features and code generation settings) and the IR optimisation settings
+	/// `targetConfiguration` should be that of the `JitContext` the object is for (see `JitContext::targetConfiguration()`)
+	pub fn key(&self, bitCode: &[u8], targetConfiguration: &TargetConfiguration, optimisation: Option<&PassManagerBuilder>) -> ObjectCacheKey
+	{
+		let mut hasher = ObjectCacheKeyHasher::new();
+		Self::FormatVersion.hash(&mut hasher);
+		env!("CARGO_PKG_VERSION").hash(&mut hasher);
+		bitCode.hash(&mut hasher);
+		targetConfiguration.hash(&mut hasher);
+		optimisation.hash(&mut hasher);
+		hasher.key()
+	}
+	
+	#[inline(always)]
+	pub fn contains(&self, key: ObjectCacheKey) -> bool
+	{
+		self.filePath(key).is_file()
+	}
+	
+	/// Does nothing if there is no object for `key`
+	pub fn invalidate(&self, key: ObjectCacheKey) -> Result<(), LlvmError>
+	{
+		match remove_file(self.filePath(key))
+		{
+			Err(ref error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+			Err(error) => Err(LlvmError::Io(error)),
+			Ok(()) => Ok(()),
+		}
+	}
+	
+	/// Removes every object
+	pub fn clear(&self) -> Result<(), LlvmError>
+	{
+		for (filePath, _, _) in self.cachedObjects()?
+		{
+			remove_file(filePath)?;
+		}
+		Ok(())
+	}
+	
+	/// Total size of all objects
+	pub fn sizeInBytes(&self) -> Result<u64, LlvmError>
+	{
+		Ok(self.cachedObjects()?.iter().map(|&(_, size, _)| size).sum())
+	}
+	
+	/// Returns `None` if there is no object for `key`, or it is not a valid object file (in which case it is removed)
+	pub(crate) fn get(&self, key: ObjectCacheKey) -> Result<Option<ObjectFile>, LlvmError>
+	{
+		let filePath = self.filePath(key);
+		if !filePath.is_file()
+		{
+			return Ok(None);
+		}
+		
+		let memoryBuffer = MemoryBuffer::fromFile(&filePath)?;
+		match ObjectFile::create(&memoryBuffer)
+		{
+			Ok(objectFile) => Ok(Some(objectFile)),
+			Err(_) =>
+			{
+				self.invalidate(key)?;
+				Ok(None)
+			}
+		}
+	}
+	
+	/// Compiles `module` (which is not modified) to an object for `targetConfiguration`, stores it under `key` and then removes the oldest objects if the cache is too large
+	pub(crate) fn compileAndPut(&self, key: ObjectCacheKey, module: &Module, targetConfiguration: &TargetConfiguration, optimisation: Option<&PassManagerBuilder>) -> Result<ObjectFile, LlvmError>
+	{
+		// Both optimisation and code generation modify the module they are given
+		let copy = module.useAsTemplateForNewModule()?;
+		if let Some(passManagerBuilder) = optimisation
+		{
+			passManagerBuilder.optimise(&copy)?;
+		}
+		let memoryBuffer = targetConfiguration.createTargetMachine()?.emitToMemoryBuffer(&copy, LLVMCodeGenFileType::LLVMObjectFile)?;
+		let objectFile = ObjectFile::create(&memoryBuffer)?;
+		
+		// Written to a uniquely named file and then renamed, so that another process never sees a partially written object
+		let temporaryFilePath = self.folderPath.join(RandomFileNameGenerator::new().generateRandomFileName(&key.to_string(), "-", 16, ".", "tmp"));
+		{
+			let mut file = File::create(&temporaryFilePath)?;
+			memoryBuffer.write(&mut file)?;
+		}
+		rename(&temporaryFilePath, self.filePath(key))?;
+		
+		self.enforceMaximumSize()?;
+		
+		Ok(objectFile)
+	}
+	
+	fn enforceMaximumSize(&self) -> Result<(), LlvmError>
+	{
+		let mut cachedObjects = self.cachedObjects()?;
+		let mut sizeInBytes: u64 = cachedObjects.iter().map(|&(_, size, _)| size).sum();
+		if sizeInBytes <= self.maximumSizeInBytes
+		{
+			return Ok(());
+		}
+		
+		// There is no portable way to get or set the last access time, so the least recently compiled are removed first
+		cachedObjects.sort_by_key(|&(_, _, modified)| modified);
+		for (filePath, size, _) in cachedObjects
+		{
+			if sizeInBytes <= self.maximumSizeInBytes
+			{
+				break;
+			}
+			remove_file(filePath)?;
+			sizeInBytes -= size;
+		}
+		
+		Ok(())
+	}
+	
+	fn cachedObjects(&self) -> Result<Vec<(PathBuf, u64, SystemTime)>, LlvmError>
+	{
+		let mut cachedObjects = Vec::new();
+		for entry in read_dir(&self.folderPath)?
+		{
+			let entry = entry?;
+			let filePath = entry.path();
+			if filePath.extension().map(|extension| extension == Self::FileExtension) != Some(true)
+			{
+				continue;
+			}
+			
+			let metadata = entry.metadata()?;
+			if metadata.is_file()
+			{
+				cachedObjects.push((filePath, metadata.len(), metadata.modified()?));
+			}
+		}
+		Ok(cachedObjects)
+	}
+	
+	#[inline(always)]
+	fn filePath(&self, key: ObjectCacheKey) -> PathBuf
+	{
+		self.folderPath.join(key.fileName())
+	}
+}
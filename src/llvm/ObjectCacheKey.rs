@@ -0,0 +1,30 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Identifies a compiled object file in an `ObjectCache`; a SHA-256 digest of the bit code and everything else that affects code generation
+/// The object's file name is the digest in hexadecimal
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectCacheKey(pub [u8; 32]);
+
+impl Display for ObjectCacheKey
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		for byte in self.0.iter()
+		{
+			write!(f, "{:02x}", byte)?;
+		}
+		Ok(())
+	}
+}
+
+impl ObjectCacheKey
+{
+	#[inline(always)]
+	pub(crate) fn fileName(&self) -> String
+	{
+		format!("{}.{}", self, ObjectCache::FileExtension)
+	}
+}
@@ -0,0 +1,41 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Feeds the bytes that `Hash` implementations write into a SHA-256 digest, so that an `ObjectCacheKey` can be made from the same inputs as a `Hash`
+/// Unlike `DefaultHasher`, the digest does not depend on the Rust release and collisions are not a practical concern
+pub(crate) struct ObjectCacheKeyHasher(Sha256);
+
+impl Hasher for ObjectCacheKeyHasher
+{
+	#[inline(always)]
+	fn write(&mut self, bytes: &[u8])
+	{
+		self.0.input(bytes)
+	}
+	
+	/// Only needed to implement `Hasher`; use `key()`
+	#[inline(always)]
+	fn finish(&self) -> u64
+	{
+		let digest = self.0.clone().result();
+		digest.iter().take(8).fold(0, |value, byte| (value << 8) | (*byte as u64))
+	}
+}
+
+impl ObjectCacheKeyHasher
+{
+	#[inline(always)]
+	pub(crate) fn new() -> Self
+	{
+		ObjectCacheKeyHasher(Sha256::default())
+	}
+	
+	#[inline(always)]
+	pub(crate) fn key(self) -> ObjectCacheKey
+	{
+		let mut key = [0; 32];
+		key.copy_from_slice(&self.0.result());
+		ObjectCacheKey(key)
+	}
+}
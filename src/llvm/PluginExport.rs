@@ -0,0 +1,63 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PluginExport
+{
+	pub functionName: String,
+	
+	/// Always a `LlvmType::Function`
+	pub signature: LlvmType,
+}
+
+impl PluginExport
+{
+	#[inline(always)]
+	pub fn new<S: Into<String>>(functionName: S, signature: LlvmType) -> Self
+	{
+		Self
+		{
+			functionName: functionName.into(),
+			signature: signature,
+		}
+	}
+	
+	/// eg `PluginExport::of::<unsafe extern "C" fn(u32) -> bool, _>("my_filter")`
+	#[inline(always)]
+	pub fn of<F: ExternCFunctionPointer, S: Into<String>>(functionName: S) -> Self
+	{
+		Self::new(functionName, F::signature())
+	}
+	
+	/// `!{!"functionName", <signature>* null}`; the signature is recorded as the type of a null function pointer constant
+	#[inline(always)]
+	fn toMetadataNode(&self) -> MetadataNode
+	{
+		MetadataNode(vec!
+		[
+			MetadataKind::String(self.functionName.clone()),
+			MetadataKind::Constant(Constant::NullPointer { llvmType: LlvmType::pointer(self.signature.clone()) }),
+		])
+	}
+	
+	fn fromMetadataNode(node: LLVMValueRef) -> Result<Self, LlvmError>
+	{
+		let operands = PluginManifest::metadataNodeOperands(node, 2)?;
+		
+		let functionName = PluginManifest::metadataString(operands[0])?;
+		if unlikely(functionName.contains('\0'))
+		{
+			return Err(LlvmError::invalidPluginManifest(format!("Plugin manifest export {:?} contains a NUL", functionName)));
+		}
+		
+		let nullFunctionPointer = operands[1];
+		if unlikely((unsafe { LLVMIsAConstantPointerNull(nullFunctionPointer) }).is_null())
+		{
+			return Err(LlvmError::invalidPluginManifest(format!("Plugin manifest export '{}' does not have a signature", functionName)));
+		}
+		let signature = LlvmType::fromLLVMTypeRef(LLVMTypeRefWrapper::fromLLVMTypeRef(nullFunctionPointer.typeOf()).elementType())?;
+		
+		Ok(Self::new(functionName, signature))
+	}
+}
@@ -0,0 +1,194 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Stored in a module as the named metadata `predicator.plugin.manifest`:-
+/// `!{!"name", !"version", i32 hostAbiVersion, !{exports...}}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PluginManifest
+{
+	pub name: String,
+	pub version: String,
+	pub hostAbiVersion: u32,
+	pub exports: Vec<PluginExport>,
+}
+
+impl PluginManifest
+{
+	/// Increment when the way predicator hosts plugins changes incompatibly
+	pub const HostAbiVersion: u32 = 1;
+	
+	pub const NamedMetadataKey: &'static str = "predicator.plugin.manifest";
+	
+	#[inline(always)]
+	pub fn new<N: Into<String>, V: Into<String>>(name: N, version: V, exports: Vec<PluginExport>) -> Self
+	{
+		Self
+		{
+			name: name.into(),
+			version: version.into(),
+			hostAbiVersion: Self::HostAbiVersion,
+			exports: exports,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn addToModule(&self, context: &Context, module: &Module)
+	{
+		module.addMetadata(context, Self::NamedMetadataKey, &self.toMetadataNode())
+	}
+	
+	/// Rewrites a bit code file (eg one produced by `RustPluginCompiler`) with this manifest embedded
+	pub fn injectIntoBitCodeFile(&self, context: &Context, bitCodeFilePath: &Path) -> Result<(), LlvmError>
+	{
+		let path = match CString::new(bitCodeFilePath.as_os_str().as_bytes())
+		{
+			Err(_) => return Err(LlvmError::FileIo { filePath: bitCodeFilePath.to_string_lossy().into_owned(), message: "File path contains embedded NULs".to_owned() }),
+			Ok(path) => path,
+		};
+		
+		let module = ModuleSourceCodeType::BitCode.createVerifiedModule(context, &MemoryBufferCreator::File(bitCodeFilePath))?;
+		
+		if !module.namedMetadataOperands(Self::NamedMetadataKey).is_empty()
+		{
+			return Err(LlvmError::invalidPluginManifest(format!("Bit code file '{}' already has a plugin manifest", bitCodeFilePath.display())));
+		}
+		
+		self.addToModule(context, &module);
+		
+		module.writeBitCodeToFile(&path)
+	}
+	
+	pub fn readFromModule(module: &Module) -> Result<Self, LlvmError>
+	{
+		let operands = module.namedMetadataOperands(Self::NamedMetadataKey);
+		let node = match operands.len()
+		{
+			0 => return Err(LlvmError::invalidPluginManifest("Plugin does not have a manifest")),
+			1 => operands[0],
+			_ => return Err(LlvmError::invalidPluginManifest("Plugin has more than one manifest")),
+		};
+		
+		let operands = Self::metadataNodeOperands(node, 4)?;
+		
+		let name = Self::metadataString(operands[0])?;
+		let version = Self::metadataString(operands[1])?;
+		let hostAbiVersion = Self::metadataInteger(operands[2])?;
+		if unlikely(hostAbiVersion > ::std::u32::MAX as u64)
+		{
+			return Err(LlvmError::invalidPluginManifest(format!("Plugin manifest host ABI version '{}' is too large", hostAbiVersion)));
+		}
+		
+		let exportNodes = Self::metadataNodeOperandsOfAnyLength(operands[3])?;
+		let mut exports = Vec::with_capacity(exportNodes.len());
+		for exportNode in exportNodes
+		{
+			exports.push(PluginExport::fromMetadataNode(exportNode)?);
+		}
+		
+		Ok
+		(
+			Self
+			{
+				name: name,
+				version: version,
+				hostAbiVersion: hostAbiVersion as u32,
+				exports: exports,
+			}
+		)
+	}
+	
+	/// Checks the host ABI version and that every export is defined in the module with a compatible signature
+	pub fn validate(&self, module: &Module) -> Result<(), LlvmError>
+	{
+		if self.hostAbiVersion != Self::HostAbiVersion
+		{
+			return Err(LlvmError::IncompatibleHostAbiVersion { pluginName: self.name.clone(), pluginVersion: self.version.clone(), requiredHostAbiVersion: self.hostAbiVersion });
+		}
+		
+		for export in self.exports.iter()
+		{
+			let functionValue = match module.namedFunction(&export.functionName)
+			{
+				None => return Err(LlvmError::FunctionNotFound { functionName: export.functionName.clone() }),
+				Some(functionValue) => functionValue,
+			};
+			
+			if functionValue.isDeclaration()
+			{
+				return Err(LlvmError::FunctionNotDefined { functionName: export.functionName.clone() });
+			}
+			
+			let actualSignature = functionValue.functionType();
+			if !actualSignature.isCompatibleWith(&export.signature)
+			{
+				return Err(LlvmError::functionSignatureMismatch(&export.functionName, &export.signature, actualSignature));
+			}
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn toMetadataNode(&self) -> MetadataNode
+	{
+		MetadataNode(vec!
+		[
+			MetadataKind::String(self.name.clone()),
+			MetadataKind::String(self.version.clone()),
+			MetadataKind::Constant(Constant::Integer { llvmType: LlvmType::Int32, value: self.hostAbiVersion as u64, signed: false }),
+			MetadataKind::Node(MetadataNode(self.exports.iter().map(|export| MetadataKind::Node(export.toMetadataNode())).collect())),
+		])
+	}
+	
+	#[inline(always)]
+	fn metadataNodeOperands(node: LLVMValueRef, expectedNumberOfOperands: usize) -> Result<Vec<LLVMValueRef>, LlvmError>
+	{
+		let operands = Self::metadataNodeOperandsOfAnyLength(node)?;
+		if unlikely(operands.len() != expectedNumberOfOperands)
+		{
+			Err(LlvmError::invalidPluginManifest(format!("Plugin manifest metadata node has {} operands, not {}", operands.len(), expectedNumberOfOperands)))
+		}
+		else
+		{
+			Ok(operands)
+		}
+	}
+	
+	fn metadataNodeOperandsOfAnyLength(node: LLVMValueRef) -> Result<Vec<LLVMValueRef>, LlvmError>
+	{
+		if unlikely((unsafe { LLVMIsAMDNode(node) }).is_null())
+		{
+			return Err(LlvmError::invalidPluginManifest("Plugin manifest metadata is not a node"));
+		}
+		
+		let numberOfOperands = (unsafe { LLVMGetMDNodeNumOperands(node) }) as usize;
+		let mut operands = Vec::with_capacity(numberOfOperands);
+		unsafe { LLVMGetMDNodeOperands(node, operands.as_mut_ptr()) };
+		unsafe { operands.set_len(numberOfOperands) };
+		Ok(operands)
+	}
+	
+	fn metadataString(value: LLVMValueRef) -> Result<String, LlvmError>
+	{
+		if unlikely(value.is_null() || (unsafe { LLVMIsAMDString(value) }).is_null())
+		{
+			return Err(LlvmError::invalidPluginManifest("Plugin manifest metadata is not a string"));
+		}
+		
+		let mut length = 0;
+		let pointer = unsafe { LLVMGetMDString(value, &mut length) };
+		let bytes = unsafe { from_raw_parts(pointer as *const u8, length as usize) };
+		String::from_utf8(bytes.to_vec()).map_err(|_| LlvmError::invalidPluginManifest("Plugin manifest metadata string is not UTF-8"))
+	}
+	
+	fn metadataInteger(value: LLVMValueRef) -> Result<u64, LlvmError>
+	{
+		if unlikely(value.is_null() || (unsafe { LLVMIsAConstantInt(value) }).is_null())
+		{
+			return Err(LlvmError::invalidPluginManifest("Plugin manifest metadata is not an integer"));
+		}
+		
+		Ok(unsafe { LLVMConstIntGetZExtValue(value) })
+	}
+}
@@ -0,0 +1,18 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Resolves symbols from the host process and the shared libraries loaded into it using `dlsym()`, eg `memcpy` from libc
+/// Statically linked host executables only contain symbols the linker kept; consider `HashMapSymbolResolver` for these
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ProcessSymbolResolver;
+
+impl SymbolResolver for ProcessSymbolResolver
+{
+	#[inline(always)]
+	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64
+	{
+		let address = unsafe { dlsym(RTLD_DEFAULT, unmangledSymbolName(symbolName).as_ptr()) };
+		address as usize as u64
+	}
+}
@@ -0,0 +1,127 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Wraps another resolver with an allow-list and a deny-list of `SymbolPattern`s, for plugins that are only partially trusted
+/// A symbol is forbidden if it matches any denied pattern, or if there are allowed patterns and it matches none of them
+/// A plugin importing a forbidden symbol is rejected before it is added to the ORC JIT stack, in both compilation modes
+/// Symbols only introduced by code generation (eg `memcpy` for a large copy) are resolved to a function that aborts the process if called; eager loading then fails, naming them, but a lazily compiled plugin only meets them when first called
+/// This must be the outermost resolver (ie not inside a `ChainedSymbolResolver`), otherwise a later resolver would resolve forbidden symbols
+pub struct SandboxSymbolResolver<SR: SymbolResolver>
+{
+	symbolResolver: SR,
+	allowed: Vec<SymbolPattern>,
+	denied: Vec<SymbolPattern>,
+	attempts: Mutex<Vec<SymbolResolutionAttempt>>,
+	forbiddenSymbols: Mutex<Vec<CString>>,
+}
+
+impl<SR: SymbolResolver> SymbolResolver for SandboxSymbolResolver<SR>
+{
+	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64
+	{
+		let (address, outcome) = if self.isForbidden(unmangledSymbolName(symbolName))
+		{
+			Self::lock(&self.forbiddenSymbols).push(symbolName.to_owned());
+			(Self::forbiddenSymbolCalled as usize as u64, SymbolResolutionOutcome::Forbidden)
+		}
+		else
+		{
+			match self.symbolResolver.resolveSymbolAddress(symbolName)
+			{
+				SymbolNotFound => (SymbolNotFound, SymbolResolutionOutcome::NotFound),
+				address => (address, SymbolResolutionOutcome::Resolved(address)),
+			}
+		};
+		
+		Self::lock(&self.attempts).push
+		(
+			SymbolResolutionAttempt
+			{
+				symbolName: symbolName.to_owned(),
+				outcome: outcome,
+			}
+		);
+		
+		address
+	}
+	
+	#[inline(always)]
+	fn takeForbiddenSymbols(&self) -> Vec<CString>
+	{
+		replace(&mut *Self::lock(&self.forbiddenSymbols), Vec::new())
+	}
+	
+	#[inline(always)]
+	fn isForbidden(&self, symbolName: &CStr) -> bool
+	{
+		if self.denied.iter().any(|pattern| pattern.matches(symbolName))
+		{
+			return true;
+		}
+		!self.allowed.is_empty() && !self.allowed.iter().any(|pattern| pattern.matches(symbolName))
+	}
+	
+	#[inline(always)]
+	fn attachedToOrcJitStack(&self, orcJitStackReference: LLVMOrcJITStackRef)
+	{
+		self.symbolResolver.attachedToOrcJitStack(orcJitStackReference)
+	}
+}
+
+impl<SR: SymbolResolver> SandboxSymbolResolver<SR>
+{
+	#[inline(always)]
+	pub fn new(symbolResolver: SR) -> Self
+	{
+		Self
+		{
+			symbolResolver: symbolResolver,
+			allowed: Vec::new(),
+			denied: Vec::new(),
+			attempts: Mutex::new(Vec::new()),
+			forbiddenSymbols: Mutex::new(Vec::new()),
+		}
+	}
+	
+	/// eg `SandboxSymbolResolver::new(ProcessSymbolResolver).allow("memcpy").allow("llvm.*").deny("llvm.trap")`
+	#[inline(always)]
+	pub fn allow<P: Into<SymbolPattern>>(mut self, pattern: P) -> Self
+	{
+		self.allowed.push(pattern.into());
+		self
+	}
+	
+	#[inline(always)]
+	pub fn deny<P: Into<SymbolPattern>>(mut self, pattern: P) -> Self
+	{
+		self.denied.push(pattern.into());
+		self
+	}
+	
+	/// Every resolution attempt so far, in order
+	#[inline(always)]
+	pub fn attempts(&self) -> Vec<SymbolResolutionAttempt>
+	{
+		Self::lock(&self.attempts).clone()
+	}
+	
+	#[inline(always)]
+	pub fn clearAttempts(&self)
+	{
+		Self::lock(&self.attempts).clear()
+	}
+	
+	#[inline(always)]
+	fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<T>
+	{
+		mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+	
+	/// Returning `SymbolNotFound` for a forbidden symbol would make LLVM abort when the plugin is finalized, before `loadPlugins()` could report it
+	extern "C" fn forbiddenSymbolCalled()
+	{
+		let _ = writeln!(io::stderr(), "A plugin called a symbol forbidden by a SandboxSymbolResolver");
+		abort();
+	}
+}
@@ -0,0 +1,90 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A JIT context that can be cloned and shared between threads, so that plugins need only be compiled once for a process
+/// Each thread still needs its own `Context` to parse plugins with, but the resulting `SharedModuleInOrcJitStack` can be used from any thread
+/// Only eager compilation is supported, as lazy compilation needs a plugin's IR to outlive the thread's `Context`
+pub struct SharedJitContext<SR: SymbolResolver + Send + Sync + 'static>
+{
+	orcJitStack: Arc<SharedOrcJitStack>,
+	symbolResolver: Arc<SR>,
+}
+
+impl<SR: SymbolResolver + Send + Sync + 'static> Clone for SharedJitContext<SR>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			orcJitStack: self.orcJitStack.clone(),
+			symbolResolver: self.symbolResolver.clone(),
+		}
+	}
+}
+
+impl<SR: SymbolResolver + Send + Sync + 'static> SharedJitContext<SR>
+{
+	pub fn new(symbolResolver: SR, targetConfiguration: &TargetConfiguration) -> Result<Self, LlvmError>
+	{
+		let reference = targetConfiguration.createOrcJitStack()?;
+		symbolResolver.attachedToOrcJitStack(reference);
+		
+		Ok
+		(
+			Self
+			{
+				orcJitStack: Arc::new(SharedOrcJitStack::new(reference)),
+				symbolResolver: Arc::new(symbolResolver),
+			}
+		)
+	}
+	
+	/// Plugins must have a valid manifest (see `PluginManifest`)
+	/// The `context` must belong to the calling thread; the module parsed with it is dropped once the plugin has been compiled
+	pub fn loadPlugins(&self, moduleSourceCodeType: ModuleSourceCodeType, memoryBufferCreator: &MemoryBufferCreator, context: &Context) -> Result<SharedModuleInOrcJitStack, LlvmError>
+	{
+		let module = moduleSourceCodeType.createVerifiedModule(context, memoryBufferCreator)?;
+		
+		let manifest = PluginManifest::readFromModule(&module)?;
+		manifest.validate(&module)?;
+		
+		let imports = module.undefinedImports()?;
+		let symbolResolver = self.symbolResolver();
+		
+		// One lock is held from checking the imports until the exports are finalized, so that another thread's plugin can neither be added in between nor have its forbidden symbols blamed on this one
+		let (plugins, outcome) = self.orcJitStack.locked(|orcJitStackReference| -> Result<(SharedModuleInOrcJitStack, Result<(), LlvmError>), LlvmError>
+		{
+			rejectForbiddenImports(&*self.symbolResolver, &imports)?;
+			
+			let unresolvedImports: Vec<ModuleImport> = imports.into_iter().filter(|import| !import.isResolvable(orcJitStackReference, &*self.symbolResolver)).collect();
+			if unlikely(!unresolvedImports.is_empty())
+			{
+				return Err(LlvmError::UnresolvedImports(unresolvedImports));
+			}
+			rejectForbiddenSymbols(&*self.symbolResolver)?;
+			
+			let reference = CompilationMode::Eager.addToOrcJitStack(orcJitStackReference, module.reference, Self::resolveSymbol, symbolResolver);
+			let plugins = SharedModuleInOrcJitStack::new(reference, self.orcJitStack.clone(), self.symbolResolver.clone(), manifest);
+			
+			let outcome = plugins.finalizeExportsAndRejectForbiddenSymbols(orcJitStackReference, &*self.symbolResolver);
+			Ok((plugins, outcome))
+		})?;
+		
+		// A rejected plugin is dropped, and so removed, only after the lock has been released, as dropping takes the lock
+		outcome?;
+		Ok(plugins)
+	}
+	
+	extern "C" fn resolveSymbol(symbolName: *const c_char, lookupContext: *mut c_void) -> u64
+	{
+		unsafe { &*(lookupContext as *mut SR) }.resolveSymbolAddress(unsafe { CStr::from_ptr(symbolName) })
+	}
+	
+	/// Stable for as long as any clone of `self.symbolResolver` is alive, which each loaded plugin keeps
+	fn symbolResolver(&self) -> *mut c_void
+	{
+		&*self.symbolResolver as *const SR as *mut _
+	}
+}
@@ -41,15 +41,24 @@ impl SuperContext
 	}
 	
 	#[inline(always)]
-	pub fn newContext(&self) -> Result<Context, String>
+	pub fn newContext(&self) -> Result<Context, LlvmError>
 	{
 		Context::new(self.enumAttributeIdentifierCache.clone())
 	}
 	
+	/// Use `TargetConfiguration::host()` to generate code for this machine's CPU and all of its features
 	#[inline(always)]
-	pub fn newJitContext<SR: SymbolResolver>(&self, symbolResolver: SR) -> Result<(JitContext<SR>, Context), String>
+	pub fn newJitContext<SR: SymbolResolver>(&self, symbolResolver: SR, targetConfiguration: &TargetConfiguration) -> Result<(JitContext<SR>, Context), LlvmError>
 	{
 		let context = self.newContext()?;
-		JitContext::new(symbolResolver, LLVMCodeGenOptLevel::LLVMCodeGenLevelAggressive).map(|jitContext| (jitContext, context))
+		JitContext::new(symbolResolver, targetConfiguration).map(|jitContext| (jitContext, context))
+	}
+	
+	/// The returned `Context` belongs to this thread; other threads using clones of the `SharedJitContext` should call `newContext()` on their own thread's `SuperContext`
+	#[inline(always)]
+	pub fn newSharedJitContext<SR: SymbolResolver + Send + Sync + 'static>(&self, symbolResolver: SR, targetConfiguration: &TargetConfiguration) -> Result<(SharedJitContext<SR>, Context), LlvmError>
+	{
+		let context = self.newContext()?;
+		SharedJitContext::new(symbolResolver, targetConfiguration).map(|sharedJitContext| (sharedJitContext, context))
 	}
 }
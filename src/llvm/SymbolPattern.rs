@@ -0,0 +1,77 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A symbol name, or a glob pattern where `*` matches any run of characters and `?` matches exactly one character, eg `llvm.memcpy.*`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SymbolPattern(Vec<u8>);
+
+impl<'a> From<&'a str> for SymbolPattern
+{
+	#[inline(always)]
+	fn from(pattern: &'a str) -> Self
+	{
+		SymbolPattern(pattern.as_bytes().to_vec())
+	}
+}
+
+impl SymbolPattern
+{
+	#[inline(always)]
+	pub fn matches(&self, symbolName: &CStr) -> bool
+	{
+		Self::globMatches(&self.0, symbolName.to_bytes())
+	}
+	
+	fn globMatches(pattern: &[u8], name: &[u8]) -> bool
+	{
+		let mut patternIndex = 0;
+		let mut nameIndex = 0;
+		
+		// Where to resume after the most recent `*`, if the characters after it fail to match
+		let mut backtrack: Option<(usize, usize)> = None;
+		
+		while nameIndex < name.len()
+		{
+			if patternIndex < pattern.len()
+			{
+				match pattern[patternIndex]
+				{
+					b'*' =>
+					{
+						patternIndex += 1;
+						backtrack = Some((patternIndex, nameIndex));
+						continue;
+					}
+					
+					b'?' =>
+					{
+						patternIndex += 1;
+						nameIndex += 1;
+						continue;
+					}
+					
+					character => if character == name[nameIndex]
+					{
+						patternIndex += 1;
+						nameIndex += 1;
+						continue;
+					},
+				}
+			}
+			
+			match backtrack
+			{
+				None => return false,
+				Some((afterStarPatternIndex, starNameIndex)) =>
+				{
+					patternIndex = afterStarPatternIndex;
+					nameIndex = starNameIndex + 1;
+					backtrack = Some((afterStarPatternIndex, nameIndex));
+				}
+			}
+		}
+		
+		pattern[patternIndex..].iter().all(|character| *character == b'*')
+	}
+}
@@ -7,4 +7,154 @@ pub const SymbolNotFound: u64 = 0;
 pub trait SymbolResolver
 {
 	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64;
+	
+	/// Symbols a plugin referenced that this resolver's policy forbids, since this was last called; `JitContext::loadPlugins()` fails if there are any
+	#[inline(always)]
+	fn takeForbiddenSymbols(&self) -> Vec<CString>
+	{
+		Vec::new()
+	}
+	
+	/// Whether this resolver's policy forbids `symbolName`, which is not mangled; used to check a plugin's imports before it is added to the ORC JIT stack
+	#[inline(always)]
+	fn isForbidden(&self, _symbolName: &CStr) -> bool
+	{
+		false
+	}
+	
+	/// Called when a `JitContext` or `SharedJitContext` is created with this resolver, so that a resolver can avoid searching the ORC JIT stack that is asking it to resolve a symbol
+	#[inline(always)]
+	fn attachedToOrcJitStack(&self, _orcJitStackReference: LLVMOrcJITStackRef)
+	{
+	}
+}
+
+impl<SR: SymbolResolver + ?Sized> SymbolResolver for Box<SR>
+{
+	#[inline(always)]
+	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64
+	{
+		(**self).resolveSymbolAddress(symbolName)
+	}
+	
+	#[inline(always)]
+	fn takeForbiddenSymbols(&self) -> Vec<CString>
+	{
+		(**self).takeForbiddenSymbols()
+	}
+	
+	#[inline(always)]
+	fn isForbidden(&self, symbolName: &CStr) -> bool
+	{
+		(**self).isForbidden(symbolName)
+	}
+	
+	#[inline(always)]
+	fn attachedToOrcJitStack(&self, orcJitStackReference: LLVMOrcJITStackRef)
+	{
+		(**self).attachedToOrcJitStack(orcJitStackReference)
+	}
+}
+
+/// Allows a resolver to be kept and changed (eg `LoadedModulesSymbolResolver`) after it has been given to a `JitContext`
+impl<SR: SymbolResolver + ?Sized> SymbolResolver for Rc<SR>
+{
+	#[inline(always)]
+	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64
+	{
+		(**self).resolveSymbolAddress(symbolName)
+	}
+	
+	#[inline(always)]
+	fn takeForbiddenSymbols(&self) -> Vec<CString>
+	{
+		(**self).takeForbiddenSymbols()
+	}
+	
+	#[inline(always)]
+	fn isForbidden(&self, symbolName: &CStr) -> bool
+	{
+		(**self).isForbidden(symbolName)
+	}
+	
+	#[inline(always)]
+	fn attachedToOrcJitStack(&self, orcJitStackReference: LLVMOrcJITStackRef)
+	{
+		(**self).attachedToOrcJitStack(orcJitStackReference)
+	}
+}
+
+impl<SR: SymbolResolver + ?Sized> SymbolResolver for Arc<SR>
+{
+	#[inline(always)]
+	fn resolveSymbolAddress<'a>(&self, symbolName: &'a CStr) -> u64
+	{
+		(**self).resolveSymbolAddress(symbolName)
+	}
+	
+	#[inline(always)]
+	fn takeForbiddenSymbols(&self) -> Vec<CString>
+	{
+		(**self).takeForbiddenSymbols()
+	}
+	
+	#[inline(always)]
+	fn isForbidden(&self, symbolName: &CStr) -> bool
+	{
+		(**self).isForbidden(symbolName)
+	}
+	
+	#[inline(always)]
+	fn attachedToOrcJitStack(&self, orcJitStackReference: LLVMOrcJITStackRef)
+	{
+		(**self).attachedToOrcJitStack(orcJitStackReference)
+	}
+}
+
+/// Called before a plugin is added to the ORC JIT stack, in both compilation modes, so that a lazily compiled plugin can not reach a forbidden symbol's stub
+/// Any forbidden symbols left over from an earlier plugin (eg recorded by a lazy compile callback) are discarded first, so that they are not blamed on this one
+#[inline(always)]
+pub(crate) fn rejectForbiddenImports<SR: SymbolResolver + ?Sized>(symbolResolver: &SR, imports: &[ModuleImport]) -> Result<(), LlvmError>
+{
+	symbolResolver.takeForbiddenSymbols();
+	
+	let forbiddenSymbols: Vec<CString> = imports.iter().filter(|import| symbolResolver.isForbidden(&import.name)).map(|import| import.name.clone()).collect();
+	if unlikely(!forbiddenSymbols.is_empty())
+	{
+		Err(LlvmError::ForbiddenSymbols(forbiddenSymbols))
+	}
+	else
+	{
+		Ok(())
+	}
+}
+
+/// Called after a plugin has been finalized, so that it is removed (by being dropped) rather than returned if it uses forbidden symbols
+#[inline(always)]
+pub(crate) fn rejectForbiddenSymbols<SR: SymbolResolver + ?Sized>(symbolResolver: &SR) -> Result<(), LlvmError>
+{
+	let forbiddenSymbols = symbolResolver.takeForbiddenSymbols();
+	if unlikely(!forbiddenSymbols.is_empty())
+	{
+		Err(LlvmError::ForbiddenSymbols(forbiddenSymbols))
+	}
+	else
+	{
+		Ok(())
+	}
+}
+
+/// Symbol names passed to a resolver are mangled for the target; on Mac OS X this means a leading underscore, which `dlsym()` and `LLVMOrcGetSymbolAddress()` do not expect
+#[inline(always)]
+pub(crate) fn unmangledSymbolName(symbolName: &CStr) -> &CStr
+{
+	if cfg!(target_os = "macos")
+	{
+		let bytes = symbolName.to_bytes_with_nul();
+		if bytes[0] == b'_'
+		{
+			return unsafe { CStr::from_bytes_with_nul_unchecked(&bytes[1..]) };
+		}
+	}
+	symbolName
 }
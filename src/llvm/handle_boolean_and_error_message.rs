@@ -5,21 +5,26 @@
 macro_rules! handle_boolean_and_error_message
 {
 	($boolean: ident, $errorMessage: ident, $functionName: ident) =>
+	{
+		handle_boolean_and_error_message!($boolean, $errorMessage, $functionName, |message| $crate::llvm::LlvmError::LlvmFunctionFailed { functionName: stringify!($functionName), message: message })
+	};
+	
+	($boolean: ident, $errorMessage: ident, $functionName: ident, $error: expr) =>
 	{
 		{
 			if $crate::rust_extra::unlikely(!$errorMessage.is_null())
 			{
 				if $crate::rust_extra::unlikely($boolean != 0)
 				{
-					let message = format!("{}:{:?}", stringify!($functionName), unsafe { ::std::ffi::CStr::from_ptr($errorMessage) });
+					let message = (unsafe { ::std::ffi::CStr::from_ptr($errorMessage) }).to_string_lossy().into_owned();
 					unsafe { $crate::llvm_sys::core::LLVMDisposeMessage($errorMessage) };
-					return Err(message)
+					return Err(($error)(message))
 				}
 				unsafe { $crate::llvm_sys::core::LLVMDisposeMessage($errorMessage) };
 			}
 			if $crate::rust_extra::unlikely($boolean != 0)
 			{
-				return Err(format!("{}:(unknown)", stringify!($functionName)))
+				return Err(($error)("(unknown)".to_owned()))
 			}
 		}
 	}
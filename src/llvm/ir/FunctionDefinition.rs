@@ -23,6 +23,7 @@ pub struct FunctionDefinition
 
 impl FunctionDefinition
 {
+	/// Has no `target-cpu` or `target-features` attributes, so is compiled for the CPU and features of the JIT context's `TargetConfiguration`
 	#[inline(always)]
 	pub fn public(name: &str, returns: FunctionParameter, parameters: Vec<(String, FunctionParameter)>) -> Self
 	{
@@ -38,8 +39,6 @@ impl FunctionDefinition
 			sspstrong,
 		};
 		
-		use self::TargetFeature::*;
-		use self::ToggledTargetFeature::*;
 		let targetDependentFunctionAttributes = hashset!
 		{
 			TargetDependentFunctionAttribute::stack_protector_buffer_size(PowerOfTwoThirtyTwoBit::_8),
@@ -57,9 +56,6 @@ impl FunctionDefinition
 			TargetDependentFunctionAttribute::no_trapping_math(false),
 			TargetDependentFunctionAttribute::unsafe_fp_math(false),
 			TargetDependentFunctionAttribute::use_soft_float(false),
-			
-			//TargetDependentFunctionAttribute::target_cpu(b"core2"),
-			TargetDependentFunctionAttribute::target_features(vec![On(cx16), On(fxsr), On(cx16), On(sse), On(sse2), On(ssse3), On(x87)]),
 		};
 		
 		Self
@@ -120,8 +120,260 @@ impl ToReference<LLVMTypeRefWrapper> for LlvmType
 	}
 }
 
+/// Prints LLVM's textual type syntax, eg `{ i32, [4 x i8] }*` or `void (i8 addrspace(1)*, ...)`
+impl Display for LlvmType
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::LlvmType::*;
+		
+		match *self
+		{
+			Int1 => write!(f, "i1"),
+			Int8 => write!(f, "i8"),
+			Int16 => write!(f, "i16"),
+			Int32 => write!(f, "i32"),
+			Int64 => write!(f, "i64"),
+			Int128 => write!(f, "i128"),
+			
+			Float16 => write!(f, "half"),
+			Float32 => write!(f, "float"),
+			Float64 => write!(f, "double"),
+			Float128 => write!(f, "fp128"),
+			Float80ForX86 => write!(f, "x86_fp80"),
+			Float128ForPowerPCLegacy => write!(f, "ppc_fp128"),
+			
+			Void => write!(f, "void"),
+			Label => write!(f, "label"),
+			MmxX86 => write!(f, "x86_mmx"),
+			
+			Struct { name: Some(ref name), .. } => Self::writeStructName(f, name),
+			
+			Struct { name: None, isPacked, ref elements } =>
+			{
+				if isPacked
+				{
+					write!(f, "<")?;
+				}
+				
+				if elements.is_empty()
+				{
+					write!(f, "{{}}")?;
+				}
+				else
+				{
+					write!(f, "{{ ")?;
+					Self::writeCommaSeparated(f, elements)?;
+					write!(f, " }}")?;
+				}
+				
+				if isPacked
+				{
+					write!(f, ">")?;
+				}
+				Ok(())
+			}
+			
+			Function { ref returns, ref parameters, hasVarArgs } =>
+			{
+				write!(f, "{} (", returns)?;
+				Self::writeCommaSeparated(f, parameters)?;
+				if hasVarArgs
+				{
+					if parameters.is_empty()
+					{
+						write!(f, "...")?;
+					}
+					else
+					{
+						write!(f, ", ...")?;
+					}
+				}
+				write!(f, ")")
+			}
+			
+			Array { ref elementType, numberOfElements } => write!(f, "[{} x {}]", numberOfElements, elementType),
+			
+			Vector { ref elementType, numberOfElements } => write!(f, "<{} x {}>", numberOfElements, elementType),
+			
+			Pointer { ref elementType, addressSpace: 0 } => write!(f, "{}*", elementType),
+			
+			Pointer { ref elementType, addressSpace } => write!(f, "{} addrspace({})*", elementType, addressSpace),
+		}
+	}
+}
+
 impl LlvmType
 {
+	/// Same rules as `LLVMTypeRefWrapper::isCompatibleWith()`: pointers are compatible if they are in the same address space, and struct names are ignored
+	pub fn isCompatibleWith(&self, other: &LlvmType) -> bool
+	{
+		use self::LlvmType::*;
+		
+		match (self, other)
+		{
+			(&Pointer { addressSpace: ref left, .. }, &Pointer { addressSpace: ref right, .. }) => left == right,
+			
+			(&Array { elementType: ref leftElementType, numberOfElements: ref leftNumberOfElements }, &Array { elementType: ref rightElementType, numberOfElements: ref rightNumberOfElements }) => leftNumberOfElements == rightNumberOfElements && leftElementType.isCompatibleWith(rightElementType),
+			
+			(&Vector { elementType: ref leftElementType, numberOfElements: ref leftNumberOfElements }, &Vector { elementType: ref rightElementType, numberOfElements: ref rightNumberOfElements }) => leftNumberOfElements == rightNumberOfElements && leftElementType.isCompatibleWith(rightElementType),
+			
+			(&Struct { isPacked: ref leftIsPacked, elements: ref leftElements, .. }, &Struct { isPacked: ref rightIsPacked, elements: ref rightElements, .. }) => leftIsPacked == rightIsPacked && Self::areAllCompatible(leftElements, rightElements),
+			
+			(&Function { returns: ref leftReturns, parameters: ref leftParameters, hasVarArgs: ref leftHasVarArgs }, &Function { returns: ref rightReturns, parameters: ref rightParameters, hasVarArgs: ref rightHasVarArgs }) => leftHasVarArgs == rightHasVarArgs && leftReturns.isCompatibleWith(rightReturns) && Self::areAllCompatible(leftParameters, rightParameters),
+			
+			(&Struct { .. }, _) | (&Function { .. }, _) | (&Array { .. }, _) | (&Vector { .. }, _) | (&Pointer { .. }, _) => false,
+			
+			_ => self == other,
+		}
+	}
+	
+	#[inline(always)]
+	fn writeCommaSeparated(f: &mut Formatter, llvmTypes: &[LlvmType]) -> fmt::Result
+	{
+		let mut afterFirst = false;
+		for llvmType in llvmTypes.iter()
+		{
+			if afterFirst
+			{
+				write!(f, ", ")?;
+			}
+			write!(f, "{}", llvmType)?;
+			afterFirst = true;
+		}
+		Ok(())
+	}
+	
+	/// Names that are not plain identifiers are quoted, with unprintable bytes, quotes and backslashes escaped as `\XX`, as LLVM does
+	fn writeStructName(f: &mut Formatter, name: &CStr) -> fmt::Result
+	{
+		let bytes = name.to_bytes();
+		
+		let isPlainIdentifier = match bytes.first()
+		{
+			None => false,
+			Some(&firstByte) => !(firstByte >= b'0' && firstByte <= b'9') && bytes.iter().all(|&byte| (byte >= b'a' && byte <= b'z') || (byte >= b'A' && byte <= b'Z') || (byte >= b'0' && byte <= b'9') || b"-$._".contains(&byte)),
+		};
+		
+		if isPlainIdentifier
+		{
+			return write!(f, "%{}", name.to_string_lossy());
+		}
+		
+		write!(f, "%\"")?;
+		for &byte in bytes.iter()
+		{
+			if byte >= b' ' && byte <= b'~' && byte != b'"' && byte != b'\\'
+			{
+				write!(f, "{}", byte as char)?;
+			}
+			else
+			{
+				write!(f, "\\{:02X}", byte)?;
+			}
+		}
+		write!(f, "\"")
+	}
+	
+	#[inline(always)]
+	fn areAllCompatible(left: &[LlvmType], right: &[LlvmType]) -> bool
+	{
+		left.len() == right.len() && left.iter().zip(right.iter()).all(|(left, right)| left.isCompatibleWith(right))
+	}
+	
+	/// The reverse of `toReference()`
+	/// Recursive named structs (eg `%Node = type { %Node* }`) are cut short at the point of recursion with an empty body
+	#[inline(always)]
+	pub fn fromLLVMTypeRef(typeRef: LLVMTypeRefWrapper) -> Result<Self, LlvmError>
+	{
+		Self::fromLLVMTypeRefRecursively(typeRef, &mut HashSet::new())
+	}
+	
+	fn fromLLVMTypeRefRecursively(typeRef: LLVMTypeRefWrapper, namedStructsBeingConverted: &mut HashSet<CString>) -> Result<Self, LlvmError>
+	{
+		use self::LLVMTypeKind::*;
+		use self::LlvmType::*;
+		
+		let llvmType = match typeRef.typeKind()
+		{
+			LLVMVoidTypeKind => Void,
+			
+			LLVMIntegerTypeKind => match typeRef.integerWidth()
+			{
+				1 => Int1,
+				8 => Int8,
+				16 => Int16,
+				32 => Int32,
+				64 => Int64,
+				128 => Int128,
+				width => return Err(LlvmError::unsupportedType(format!("Integer width '{}'", width))),
+			},
+			
+			LLVMHalfTypeKind => Float16,
+			LLVMFloatTypeKind => Float32,
+			LLVMDoubleTypeKind => Float64,
+			LLVMFP128TypeKind => Float128,
+			LLVMX86_FP80TypeKind => Float80ForX86,
+			LLVMPPC_FP128TypeKind => Float128ForPowerPCLegacy,
+			
+			LLVMLabelTypeKind => Label,
+			LLVMX86_MMXTypeKind => MmxX86,
+			
+			LLVMStructTypeKind =>
+			{
+				let name = typeRef.structName();
+				let isPacked = typeRef.isPackedStruct();
+				
+				if let Some(ref name) = name
+				{
+					if namedStructsBeingConverted.contains(name)
+					{
+						return Ok(Struct { name: Some(name.clone()), isPacked: isPacked, elements: Vec::new() });
+					}
+					namedStructsBeingConverted.insert(name.clone());
+				}
+				
+				let mut elements = Vec::new();
+				for elementType in typeRef.structElementTypes()
+				{
+					elements.push(Self::fromLLVMTypeRefRecursively(elementType, namedStructsBeingConverted)?);
+				}
+				
+				if let Some(ref name) = name
+				{
+					namedStructsBeingConverted.remove(name);
+				}
+				
+				Struct { name: name, isPacked: isPacked, elements: elements }
+			}
+			
+			LLVMFunctionTypeKind =>
+			{
+				let returns = Self::fromLLVMTypeRefRecursively(typeRef.returnType(), namedStructsBeingConverted)?;
+				
+				let mut parameters = Vec::new();
+				for parameterType in typeRef.parameterTypes()
+				{
+					parameters.push(Self::fromLLVMTypeRefRecursively(parameterType, namedStructsBeingConverted)?);
+				}
+				
+				Function { returns: Box::new(returns), parameters: parameters, hasVarArgs: typeRef.isFunctionVarArgs() }
+			}
+			
+			LLVMArrayTypeKind => Array { elementType: Box::new(Self::fromLLVMTypeRefRecursively(typeRef.elementType(), namedStructsBeingConverted)?), numberOfElements: typeRef.arrayLength() },
+			
+			LLVMVectorTypeKind => Vector { elementType: Box::new(Self::fromLLVMTypeRefRecursively(typeRef.elementType(), namedStructsBeingConverted)?), numberOfElements: typeRef.vectorSize() },
+			
+			LLVMPointerTypeKind => Pointer { elementType: Box::new(Self::fromLLVMTypeRefRecursively(typeRef.elementType(), namedStructsBeingConverted)?), addressSpace: typeRef.pointerAddressSpace() },
+			
+			LLVMMetadataTypeKind => return Err(LlvmError::unsupportedType("Metadata")),
+			
+			LLVMTokenTypeKind => return Err(LlvmError::unsupportedType("Token")),
+		};
+		
+		Ok(llvmType)
+	}
+	
 	#[inline(always)]
 	pub fn int8Pointer() -> Self
 	{
@@ -16,10 +16,17 @@ pub struct ModuleDefinition
 impl ModuleDefinition
 {
 	#[inline(always)]
-	pub fn newForHost<S: Into<String> + Clone>(name: S) -> Result<Self, String>
+	pub fn newForHost<S: Into<String> + Clone>(name: S) -> Result<Self, LlvmError>
 	{
-		let targetTriple = Target::defaultTargetTriple();
-		let targetMachineDataLayout = Target::createHostTargetMachine(LLVMCodeGenOptLevel::LLVMCodeGenLevelNone)?.targetMachineDataLayout();
+		Self::new(name, &TargetConfiguration::host()?)
+	}
+	
+	/// Functions are compiled for the CPU and features of the JIT context's `TargetConfiguration`; `targetConfiguration` only determines the data layout, unless `targetDependentFunctionAttributes()` are applied to functions
+	#[inline(always)]
+	pub fn new<S: Into<String> + Clone>(name: S, targetConfiguration: &TargetConfiguration) -> Result<Self, LlvmError>
+	{
+		let targetTriple = targetConfiguration.targetTriple().to_owned();
+		let targetMachineDataLayout = targetConfiguration.createTargetMachine()?.targetMachineDataLayout();
 		
 		Ok
 		(
@@ -39,7 +46,7 @@ impl ModuleDefinition
 	}
 	
 	#[inline(always)]
-	pub fn create(&self, context: &Context) -> Result<Module, String>
+	pub fn create(&self, context: &Context) -> Result<Module, LlvmError>
 	{
 		let module = context.createModule(&self.name, &self.identifier, &self.targetTriple, &self.targetMachineDataLayout, self.inlineAssembler.as_ref().map(String::as_str))?;
 		
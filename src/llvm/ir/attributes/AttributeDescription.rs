@@ -0,0 +1,61 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// An attribute read back from a function, eg by `FunctionValue::functionAttributes()`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AttributeDescription
+{
+	/// `name` is `None` if the attribute is not one this crate knows about; `value` is zero for attributes without one
+	Enum { name: Option<EnumAttributeName>, identifier: EnumAttributeIdentifier, value: u64 },
+	
+	/// eg `"target-cpu"="haswell"`
+	String { key: String, value: String },
+}
+
+impl Display for AttributeDescription
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::AttributeDescription::*;
+		
+		match *self
+		{
+			Enum { name: Some(name), value: 0, .. } => write!(f, "{}", name.name()),
+			Enum { name: Some(name), value, .. } => write!(f, "{}({})", name.name(), value),
+			Enum { name: None, identifier, value } => write!(f, "#{}({})", identifier.0, value),
+			String { ref key, ref value } => write!(f, "\"{}\"=\"{}\"", key, value),
+		}
+	}
+}
+
+impl AttributeDescription
+{
+	#[inline(always)]
+	pub(crate) fn fromLLVMAttributeRef(reference: LLVMAttributeRef) -> Self
+	{
+		if unsafe { LLVMIsEnumAttribute(reference) } != 0
+		{
+			let identifier = EnumAttributeIdentifier(unsafe { LLVMGetEnumAttributeKind(reference) });
+			AttributeDescription::Enum
+			{
+				name: EnumAttributeName::fromIdentifier(identifier),
+				identifier: identifier,
+				value: unsafe { LLVMGetEnumAttributeValue(reference) },
+			}
+		}
+		else
+		{
+			let mut keyLength = 0;
+			let key = unsafe { LLVMGetStringAttributeKind(reference, &mut keyLength) };
+			let mut valueLength = 0;
+			let value = unsafe { LLVMGetStringAttributeValue(reference, &mut valueLength) };
+			
+			AttributeDescription::String
+			{
+				key: String::from_utf8_lossy(unsafe { from_raw_parts(key as *const u8, keyLength as usize) }).into_owned(),
+				value: String::from_utf8_lossy(unsafe { from_raw_parts(value as *const u8, valueLength as usize) }).into_owned(),
+			}
+		}
+	}
+}
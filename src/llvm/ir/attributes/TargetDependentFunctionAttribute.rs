@@ -7,6 +7,7 @@ pub enum TargetDependentFunctionAttribute
 {
 	StringValueless(&'static [u8]),
 	StringValue(&'static [u8], &'static [u8]),
+	StringOwnedValue(&'static [u8], Vec<u8>),
 	StringBoolean(&'static [u8], bool),
 	StringPowerOfTwo(&'static [u8], PowerOfTwoThirtyTwoBit),
 	StringFeatures(&'static [u8], Vec<ToggledTargetFeature>)
@@ -95,12 +96,24 @@ impl TargetDependentFunctionAttribute
 		TargetDependentFunctionAttribute::StringValue(b"target-cpu", name)
 	}
 	
+	/// eg from `llvmHostCpuName()`
+	pub fn target_cpu_owned(name: Vec<u8>) -> Self
+	{
+		TargetDependentFunctionAttribute::StringOwnedValue(b"target-cpu", name)
+	}
+	
 	// vec![On(cx16), On(fxsr), On(cx16), On(sse), On(sse2), On(ssse3), On(x87)]
 	pub fn target_features(features: Vec<ToggledTargetFeature>) -> Self
 	{
 		TargetDependentFunctionAttribute::StringFeatures(b"target-features", features)
 	}
 	
+	/// An LLVM feature string, eg `+avx2,-sse4a`, which may contain features not in `TargetFeature`
+	pub fn target_features_owned(features: Vec<u8>) -> Self
+	{
+		TargetDependentFunctionAttribute::StringOwnedValue(b"target-features", features)
+	}
+	
 	#[inline(always)]
 	pub(crate) fn addToFunction(&self, context: &Context, functionValue: FunctionValue)
 	{
@@ -112,6 +125,8 @@ impl TargetDependentFunctionAttribute
 			
 			StringValue(name, value) => context.LLVMAddTargetDependentFunctionAttr(functionValue, name, Some(value)),
 			
+			StringOwnedValue(name, ref value) => context.LLVMAddTargetDependentFunctionAttr(functionValue, name, Some(&value[..])),
+			
 			StringBoolean(name, boolean) =>
 			{
 				if boolean
@@ -71,6 +71,80 @@ pub enum EnumAttributeName
 
 impl EnumAttributeName
 {
+	const All: [EnumAttributeName; 56] =
+	[
+		EnumAttributeName::alignstack,
+		EnumAttributeName::allocsize,
+		EnumAttributeName::alwaysinline,
+		EnumAttributeName::builtin,
+		EnumAttributeName::cold,
+		EnumAttributeName::convergent,
+		EnumAttributeName::inaccessiblememonly,
+		EnumAttributeName::inaccessiblemem_or_argmemonly,
+		EnumAttributeName::inlinehint,
+		EnumAttributeName::jumptable,
+		EnumAttributeName::minsize,
+		EnumAttributeName::naked,
+		EnumAttributeName::nobuiltin,
+		EnumAttributeName::noduplicate,
+		EnumAttributeName::noimplicitfloat,
+		EnumAttributeName::noinline,
+		EnumAttributeName::nonlazybind,
+		EnumAttributeName::noredzone,
+		EnumAttributeName::noreturn,
+		EnumAttributeName::norecurse,
+		EnumAttributeName::nounwind,
+		EnumAttributeName::optnone,
+		EnumAttributeName::optsize,
+		EnumAttributeName::patchable_function,
+		EnumAttributeName::probe_stack,
+		EnumAttributeName::stack_probe_size,
+		EnumAttributeName::argmemonly,
+		EnumAttributeName::safestack,
+		EnumAttributeName::sanitize_address,
+		EnumAttributeName::sanitize_memory,
+		EnumAttributeName::sanitize_thread,
+		EnumAttributeName::speculatable,
+		EnumAttributeName::ssp,
+		EnumAttributeName::sspreq,
+		EnumAttributeName::sspstrong,
+		EnumAttributeName::thunk,
+		EnumAttributeName::uwtable,
+		EnumAttributeName::zeroext,
+		EnumAttributeName::signext,
+		EnumAttributeName::inreg,
+		EnumAttributeName::byval,
+		EnumAttributeName::inalloca,
+		EnumAttributeName::sret,
+		EnumAttributeName::align,
+		EnumAttributeName::noalias,
+		EnumAttributeName::nocapture,
+		EnumAttributeName::nest,
+		EnumAttributeName::returned,
+		EnumAttributeName::nonnull,
+		EnumAttributeName::dereferenceable,
+		EnumAttributeName::dereferenceable_or_null,
+		EnumAttributeName::swiftself,
+		EnumAttributeName::swifterror,
+		EnumAttributeName::readnone,
+		EnumAttributeName::readonly,
+		EnumAttributeName::writeonly,
+	];
+	
+	/// `None` if the attribute is not one this crate knows about
+	#[inline(always)]
+	pub fn fromIdentifier(identifier: EnumAttributeIdentifier) -> Option<Self>
+	{
+		Self::All.iter().find(|enumAttributeName| enumAttributeName.identifier() == identifier).cloned()
+	}
+	
+	/// As in LLVM's textual syntax, eg `patchable-function`
+	#[inline(always)]
+	pub fn name(self) -> &'static str
+	{
+		from_utf8(self.cString()).unwrap()
+	}
+	
 	#[inline(always)]
 	pub fn identifier(self) -> EnumAttributeIdentifier
 	{
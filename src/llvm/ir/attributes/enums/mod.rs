@@ -6,6 +6,7 @@ use ::llvm_sys::core::*;
 use ::std::cell::RefCell;
 use ::std::collections::HashMap;
 use ::std::rc::Rc;
+use ::std::str::from_utf8;
 
 
 include!("EnumAttributeIdentifier.rs");
@@ -8,6 +8,7 @@ use super::*;
 pub mod enums;
 
 
+include!("AttributeDescription.rs");
 include!("CallParameterAttribute.rs");
 include!("FunctionAttribute.rs");
 include!("ParameterAttribute.rs");
@@ -5,7 +5,6 @@
 use super::*;
 use self::builder::*;
 use self::useful::*;
-use ::llvm_sys::target_machine::LLVMCodeGenOptLevel;
 #[allow(unused_imports)] use ::rust_extra::powersOfTwo::AsU32;
 use ::rust_extra::powersOfTwo::PowerOfTwoThirtyTwoBit;
 use ::std::cmp::Eq;
@@ -68,4 +68,172 @@ impl LLVMTypeRefWrapper
 	{
 		unsafe { LLVMGetTypeContext(self.0) }
 	}
+	
+	#[inline(always)]
+	pub fn integerWidth(&self) -> u32
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMIntegerTypeKind, "not an integer type");
+		
+		unsafe { LLVMGetIntTypeWidth(self.0) }
+	}
+	
+	#[inline(always)]
+	pub fn elementType(&self) -> LLVMTypeRefWrapper
+	{
+		LLVMTypeRefWrapper::fromLLVMTypeRef(unsafe { LLVMGetElementType(self.0) })
+	}
+	
+	#[inline(always)]
+	pub fn pointerAddressSpace(&self) -> u32
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMPointerTypeKind, "not a pointer type");
+		
+		unsafe { LLVMGetPointerAddressSpace(self.0) }
+	}
+	
+	#[inline(always)]
+	pub fn returnType(&self) -> LLVMTypeRefWrapper
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMFunctionTypeKind, "not a function type");
+		
+		LLVMTypeRefWrapper::fromLLVMTypeRef(unsafe { LLVMGetReturnType(self.0) })
+	}
+	
+	#[inline(always)]
+	pub fn parameterTypes(&self) -> Vec<LLVMTypeRefWrapper>
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMFunctionTypeKind, "not a function type");
+		
+		let numberOfParameters = (unsafe { LLVMCountParamTypes(self.0) }) as usize;
+		let mut parameterTypes = Vec::with_capacity(numberOfParameters);
+		unsafe { LLVMGetParamTypes(self.0, parameterTypes.as_mut_ptr()) };
+		unsafe { parameterTypes.set_len(numberOfParameters) };
+		parameterTypes.iter().map(|parameterType| LLVMTypeRefWrapper::fromLLVMTypeRef(*parameterType)).collect()
+	}
+	
+	#[inline(always)]
+	pub fn isFunctionVarArgs(&self) -> bool
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMFunctionTypeKind, "not a function type");
+		
+		(unsafe { LLVMIsFunctionVarArg(self.0) }) != 0
+	}
+	
+	#[inline(always)]
+	pub fn arrayLength(&self) -> u32
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMArrayTypeKind, "not an array type");
+		
+		unsafe { LLVMGetArrayLength(self.0) }
+	}
+	
+	#[inline(always)]
+	pub fn vectorSize(&self) -> u32
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMVectorTypeKind, "not a vector type");
+		
+		unsafe { LLVMGetVectorSize(self.0) }
+	}
+	
+	/// None for literal (anonymous) structs
+	#[inline(always)]
+	pub fn structName(&self) -> Option<CString>
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMStructTypeKind, "not a struct type");
+		
+		let name = unsafe { LLVMGetStructName(self.0) };
+		if name.is_null()
+		{
+			None
+		}
+		else
+		{
+			Some((unsafe { CStr::from_ptr(name) }).to_owned())
+		}
+	}
+	
+	#[inline(always)]
+	pub fn isPackedStruct(&self) -> bool
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMStructTypeKind, "not a struct type");
+		
+		(unsafe { LLVMIsPackedStruct(self.0) }) != 0
+	}
+	
+	#[inline(always)]
+	pub fn structElementTypes(&self) -> Vec<LLVMTypeRefWrapper>
+	{
+		debug_assert!(self.typeKind() == LLVMTypeKind::LLVMStructTypeKind, "not a struct type");
+		
+		let numberOfElements = (unsafe { LLVMCountStructElementTypes(self.0) }) as usize;
+		let mut elementTypes = Vec::with_capacity(numberOfElements);
+		unsafe { LLVMGetStructElementTypes(self.0, elementTypes.as_mut_ptr()) };
+		unsafe { elementTypes.set_len(numberOfElements) };
+		elementTypes.iter().map(|elementType| LLVMTypeRefWrapper::fromLLVMTypeRef(*elementType)).collect()
+	}
+	
+	/// Pointers are compatible if their address spaces match; what they point to is ignored, as any pointer crosses the C boundary in the same way
+	/// Struct names are also ignored
+	pub fn isCompatibleWith(&self, llvmType: &LlvmType) -> bool
+	{
+		use self::LLVMTypeKind::*;
+		use self::LlvmType::*;
+		
+		match (self.typeKind(), llvmType)
+		{
+			(LLVMVoidTypeKind, &Void) => true,
+			
+			(LLVMIntegerTypeKind, &Int1) => self.integerWidth() == 1,
+			(LLVMIntegerTypeKind, &Int8) => self.integerWidth() == 8,
+			(LLVMIntegerTypeKind, &Int16) => self.integerWidth() == 16,
+			(LLVMIntegerTypeKind, &Int32) => self.integerWidth() == 32,
+			(LLVMIntegerTypeKind, &Int64) => self.integerWidth() == 64,
+			(LLVMIntegerTypeKind, &Int128) => self.integerWidth() == 128,
+			
+			(LLVMHalfTypeKind, &Float16) => true,
+			(LLVMFloatTypeKind, &Float32) => true,
+			(LLVMDoubleTypeKind, &Float64) => true,
+			(LLVMFP128TypeKind, &Float128) => true,
+			(LLVMX86_FP80TypeKind, &Float80ForX86) => true,
+			(LLVMPPC_FP128TypeKind, &Float128ForPowerPCLegacy) => true,
+			
+			(LLVMLabelTypeKind, &Label) => true,
+			(LLVMX86_MMXTypeKind, &MmxX86) => true,
+			
+			(LLVMPointerTypeKind, &Pointer { addressSpace, .. }) => self.pointerAddressSpace() == addressSpace,
+			
+			(LLVMArrayTypeKind, &Array { ref elementType, numberOfElements }) => self.arrayLength() == numberOfElements && self.elementType().isCompatibleWith(elementType),
+			
+			(LLVMVectorTypeKind, &Vector { ref elementType, numberOfElements }) => self.vectorSize() == numberOfElements && self.elementType().isCompatibleWith(elementType),
+			
+			(LLVMStructTypeKind, &Struct { isPacked, ref elements, .. }) =>
+			{
+				if self.isPackedStruct() != isPacked
+				{
+					return false;
+				}
+				
+				let elementTypes = self.structElementTypes();
+				elementTypes.len() == elements.len() && elementTypes.iter().zip(elements.iter()).all(|(elementType, element)| elementType.isCompatibleWith(element))
+			}
+			
+			(LLVMFunctionTypeKind, &Function { ref returns, ref parameters, hasVarArgs }) =>
+			{
+				if self.isFunctionVarArgs() != hasVarArgs
+				{
+					return false;
+				}
+				
+				if !self.returnType().isCompatibleWith(returns)
+				{
+					return false;
+				}
+				
+				let parameterTypes = self.parameterTypes();
+				parameterTypes.len() == parameters.len() && parameterTypes.iter().zip(parameters.iter()).all(|(parameterType, parameter)| parameterType.isCompatibleWith(parameter))
+			}
+			
+			_ => false,
+		}
+	}
 }
@@ -22,6 +22,73 @@ impl Value for FunctionValue
 
 impl FunctionValue
 {
+	/// The type of a function value is a pointer to its function type
+	#[inline(always)]
+	pub fn functionType(&self) -> LLVMTypeRefWrapper
+	{
+		LLVMTypeRefWrapper::fromLLVMTypeRef(self.typeOf()).elementType()
+	}
+	
+	#[inline(always)]
+	pub fn signature(&self) -> Result<LlvmType, LlvmError>
+	{
+		LlvmType::fromLLVMTypeRef(self.functionType())
+	}
+	
+	#[inline(always)]
+	pub fn isDeclaration(&self) -> bool
+	{
+		(unsafe { LLVMIsDeclaration(self.asLLVMValueRef()) }) != 0
+	}
+	
+	#[inline(always)]
+	pub fn linkage(&self) -> LLVMLinkage
+	{
+		unsafe { LLVMGetLinkage(self.asLLVMValueRef()) }
+	}
+	
+	#[inline(always)]
+	pub fn visibility(&self) -> LLVMVisibility
+	{
+		unsafe { LLVMGetVisibility(self.asLLVMValueRef()) }
+	}
+	
+	/// Attributes of the function itself, rather than of its return value or parameters
+	#[inline(always)]
+	pub fn functionAttributes(&self) -> Vec<AttributeDescription>
+	{
+		self.attributesAt(LLVMAttributeFunctionIndex)
+	}
+	
+	#[inline(always)]
+	pub fn returnAttributes(&self) -> Vec<AttributeDescription>
+	{
+		self.attributesAt(LLVMAttributeReturnIndex)
+	}
+	
+	/// `index` is zero-based
+	#[inline(always)]
+	pub fn parameterAttributes(&self, index: usize) -> Vec<AttributeDescription>
+	{
+		self.attributesAt(index as u32 + 1)
+	}
+	
+	fn attributesAt(&self, attributeIndex: u32) -> Vec<AttributeDescription>
+	{
+		let numberOfAttributes = (unsafe { LLVMGetAttributeCountAtIndex(self.asLLVMValueRef(), attributeIndex) }) as usize;
+		let mut attributes = Vec::with_capacity(numberOfAttributes);
+		unsafe { LLVMGetAttributesAtIndex(self.asLLVMValueRef(), attributeIndex, attributes.as_mut_ptr()) };
+		unsafe { attributes.set_len(numberOfAttributes) };
+		attributes.into_iter().map(AttributeDescription::fromLLVMAttributeRef).collect()
+	}
+	
+	/// eg `llvm.memcpy.p0i8.p0i8.i64`; these are never resolved by a symbol resolver
+	#[inline(always)]
+	pub fn isIntrinsic(&self) -> bool
+	{
+		(unsafe { LLVMGetIntrinsicID(self.asLLVMValueRef()) }) != 0
+	}
+	
 	#[inline(always)]
 	pub fn parameterAt(&self, index: usize) -> Option<FunctionParameterValue>
 	{
@@ -22,6 +22,65 @@ impl Value for GlobalValue
 
 impl GlobalValue
 {
+	/// The type of a global value is a pointer to the type of what it holds
+	#[inline(always)]
+	pub fn valueType(&self) -> LLVMTypeRefWrapper
+	{
+		LLVMTypeRefWrapper::fromLLVMTypeRef(self.typeOf()).elementType()
+	}
+	
+	#[inline(always)]
+	pub fn llvmType(&self) -> Result<LlvmType, LlvmError>
+	{
+		LlvmType::fromLLVMTypeRef(self.valueType())
+	}
+	
+	#[inline(always)]
+	pub fn isDeclaration(&self) -> bool
+	{
+		(unsafe { LLVMIsDeclaration(self.reference()) }) != 0
+	}
+	
+	#[inline(always)]
+	pub fn linkage(&self) -> LLVMLinkage
+	{
+		unsafe { LLVMGetLinkage(self.reference()) }
+	}
+	
+	#[inline(always)]
+	pub fn visibility(&self) -> LLVMVisibility
+	{
+		unsafe { LLVMGetVisibility(self.reference()) }
+	}
+	
+	/// ie immutable; not to be confused with `isConstant()`, which is always true for a global, as its address is constant
+	#[inline(always)]
+	pub fn isGlobalConstant(&self) -> bool
+	{
+		(unsafe { LLVMIsGlobalConstant(self.reference()) }) != 0
+	}
+	
+	/// `None` for declarations
+	#[inline(always)]
+	pub fn initializer(&self) -> Option<ConstantValue>
+	{
+		let reference = unsafe { LLVMGetInitializer(self.reference()) };
+		if reference.is_null()
+		{
+			None
+		}
+		else
+		{
+			Some(ConstantValue::fromLLVMValueRef(reference))
+		}
+	}
+	
+	#[inline(always)]
+	pub fn threadLocalMode(&self) -> LLVMThreadLocalMode
+	{
+		unsafe { LLVMGetThreadLocalMode(self.reference()) }
+	}
+	
 	#[inline(always)]
 	pub fn setLinkage(&self, llvmLinkage: LLVMLinkage)
 	{
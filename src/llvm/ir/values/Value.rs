@@ -40,6 +40,13 @@ pub trait Value: Sized + Copy + Clone
 		unsafe { LLVMGetValueName(self.asLLVMValueRef()) }
 	}
 	
+	/// Empty for unnamed values
+	#[inline(always)]
+	fn name(&self) -> CString
+	{
+		(unsafe { CStr::from_ptr(self.getName()) }).to_owned()
+	}
+	
 	#[inline(always)]
 	fn setName(&self, name: *const c_char)
 	{
@@ -14,7 +14,7 @@ impl Drop for ExecutionEngine
 	fn drop(&mut self)
 	{
 		//use ::llvm_sys::execution_engine::LLVMRemoveModule as executionEngineRemoveModule;
-		//		fn removeModule(executionEngineReference: LLVMExecutionEngineRef, moduleReference: LLVMModuleRef) -> Result<(), String>
+		//		fn removeModule(executionEngineReference: LLVMExecutionEngineRef, moduleReference: LLVMModuleRef) -> Result<(), LlvmError>
 		//		{
 		//			let mut outReference = null_mut();
 		//			let mut errorMessage = null_mut();
@@ -84,5 +84,111 @@ impl ExecutionEngine
 		}
 	}
 	
-	// llvm_sys::execution_engine::LLVMRunFunction - takes a number of arguments; call LLVMFindFunction() first to get a function LLVMValueRef
+	/// NOTE: MCJIT only supports a handful of signatures (those of `main()` and nullary functions) through `LLVMRunFunction()`; others are rejected with `LlvmError::DynamicArguments` rather than being called
+	/// For anything else, load the module into an ORC JIT stack and use `ModuleInOrcJitStack::dynamicFunction()`
+	pub fn runFunction(&self, functionName: &str, arguments: &[DynamicValue]) -> Result<Option<DynamicValue>, LlvmError>
+	{
+		let functionNameCString = CString::new(functionName).expect("Contains embedded ASCII NULs");
+		let mut functionReference = null_mut();
+		let boolean = unsafe { LLVMFindFunction(self.reference, functionNameCString.as_ptr(), &mut functionReference) };
+		if unlikely(boolean != 0)
+		{
+			return Err(LlvmError::FunctionNotFound { functionName: functionName.to_owned() });
+		}
+		
+		let functionType = FunctionValue::fromLLVMValueRef(functionReference).functionType();
+		let returnType = functionType.returnType();
+		let returnKind = match DynamicValueKind::fromLLVMTypeRef(returnType)
+		{
+			None => return Err(LlvmError::unsupportedType(format!("Function '{}' has a return type '{}' that can not be returned dynamically", functionName, returnType.toString().to_string_lossy()))),
+			Some(returnKind) => returnKind,
+		};
+		
+		let parameterTypes = functionType.parameterTypes();
+		if unlikely(parameterTypes.len() != arguments.len())
+		{
+			return Err(LlvmError::dynamicArguments(format!("Function '{}' expects {} arguments but was given {}", functionName, parameterTypes.len(), arguments.len())));
+		}
+		
+		let parameterKinds: Vec<Option<DynamicValueKind>> = parameterTypes.iter().map(|parameterType| DynamicValueKind::fromLLVMTypeRef(*parameterType)).collect();
+		if unlikely(!Self::isSupportedByRunFunction(returnKind, &parameterKinds))
+		{
+			return Err(LlvmError::dynamicArguments(format!("Function '{}' has a signature '{}' that LLVMRunFunction() can not call; only those of main() and functions without parameters are supported", functionName, functionType.toString().to_string_lossy())));
+		}
+		
+		let mut genericValues = Vec::with_capacity(arguments.len());
+		for (index, (argument, parameterType)) in arguments.iter().zip(parameterTypes.iter()).enumerate()
+		{
+			if unlikely(DynamicValueKind::fromLLVMTypeRef(*parameterType) != Some(argument.kind()))
+			{
+				for genericValue in genericValues
+				{
+					unsafe { LLVMDisposeGenericValue(genericValue) };
+				}
+				return Err(LlvmError::dynamicArguments(format!("Argument {} of function '{}' should be of type '{}' but was {:?}", index, functionName, parameterType.toString().to_string_lossy(), argument)));
+			}
+			
+			use self::DynamicValue::*;
+			let genericValue = match *argument
+			{
+				Int1(value) => unsafe { LLVMCreateGenericValueOfInt(parameterType.asLLVMTypeRef(), value as u64, 0) },
+				Int8(value) => unsafe { LLVMCreateGenericValueOfInt(parameterType.asLLVMTypeRef(), value as u64, 0) },
+				Int16(value) => unsafe { LLVMCreateGenericValueOfInt(parameterType.asLLVMTypeRef(), value as u64, 0) },
+				Int32(value) => unsafe { LLVMCreateGenericValueOfInt(parameterType.asLLVMTypeRef(), value as u64, 0) },
+				Int64(value) => unsafe { LLVMCreateGenericValueOfInt(parameterType.asLLVMTypeRef(), value, 0) },
+				Float32(value) => unsafe { LLVMCreateGenericValueOfFloat(parameterType.asLLVMTypeRef(), value as f64) },
+				Float64(value) => unsafe { LLVMCreateGenericValueOfFloat(parameterType.asLLVMTypeRef(), value) },
+				Pointer(value) => unsafe { LLVMCreateGenericValueOfPointer(value) },
+			};
+			genericValues.push(genericValue);
+		}
+		
+		let result = unsafe { LLVMRunFunction(self.reference, functionReference, genericValues.len() as u32, genericValues.as_mut_ptr()) };
+		
+		for genericValue in genericValues
+		{
+			unsafe { LLVMDisposeGenericValue(genericValue) };
+		}
+		
+		let value = match returnKind
+		{
+			DynamicValueKind::Void => None,
+			DynamicValueKind::Int1 => Some(DynamicValue::Int1(unsafe { LLVMGenericValueToInt(result, 0) } != 0)),
+			DynamicValueKind::Int8 => Some(DynamicValue::Int8(unsafe { LLVMGenericValueToInt(result, 0) } as u8)),
+			DynamicValueKind::Int16 => Some(DynamicValue::Int16(unsafe { LLVMGenericValueToInt(result, 0) } as u16)),
+			DynamicValueKind::Int32 => Some(DynamicValue::Int32(unsafe { LLVMGenericValueToInt(result, 0) } as u32)),
+			DynamicValueKind::Int64 => Some(DynamicValue::Int64(unsafe { LLVMGenericValueToInt(result, 0) })),
+			DynamicValueKind::Float32 => Some(DynamicValue::Float32(unsafe { LLVMGenericValueToFloat(returnType.asLLVMTypeRef(), result) } as f32)),
+			DynamicValueKind::Float64 => Some(DynamicValue::Float64(unsafe { LLVMGenericValueToFloat(returnType.asLLVMTypeRef(), result) })),
+			DynamicValueKind::Pointer => Some(DynamicValue::Pointer(unsafe { LLVMGenericValueToPointer(result) })),
+		};
+		
+		unsafe { LLVMDisposeGenericValue(result) };
+		
+		Ok(value)
+	}
+	
+	/// `LLVMRunFunction()` aborts the process for any other signature
+	fn isSupportedByRunFunction(returnKind: DynamicValueKind, parameterKinds: &[Option<DynamicValueKind>]) -> bool
+	{
+		use self::DynamicValueKind::*;
+		
+		if parameterKinds.is_empty()
+		{
+			return true;
+		}
+		
+		// `int main(int argc, char** argv, char** environment)`, or a prefix of it
+		if returnKind != Int32 && returnKind != Void
+		{
+			return false;
+		}
+		match parameterKinds.len()
+		{
+			1 => parameterKinds[0] == Some(Int32),
+			2 => parameterKinds[0] == Some(Int32) && parameterKinds[1] == Some(Pointer),
+			3 => parameterKinds[0] == Some(Int32) && parameterKinds[1] == Some(Pointer) && parameterKinds[2] == Some(Pointer),
+			_ => false,
+		}
+	}
 }
@@ -3,10 +3,19 @@
 
 
 use super::*;
+use ::llvm_sys::execution_engine::LLVMCreateGenericValueOfFloat;
+use ::llvm_sys::execution_engine::LLVMCreateGenericValueOfInt;
+use ::llvm_sys::execution_engine::LLVMCreateGenericValueOfPointer;
 use ::llvm_sys::execution_engine::LLVMDisposeExecutionEngine;
+use ::llvm_sys::execution_engine::LLVMDisposeGenericValue;
 use ::llvm_sys::execution_engine::LLVMExecutionEngineRef;
+use ::llvm_sys::execution_engine::LLVMFindFunction;
+use ::llvm_sys::execution_engine::LLVMGenericValueToFloat;
+use ::llvm_sys::execution_engine::LLVMGenericValueToInt;
+use ::llvm_sys::execution_engine::LLVMGenericValueToPointer;
 use ::llvm_sys::execution_engine::LLVMGetFunctionAddress;
 use ::llvm_sys::execution_engine::LLVMGetGlobalValueAddress;
+use ::llvm_sys::execution_engine::LLVMRunFunction;
 use ::rust_extra::unlikely;
 use ::std::ffi::CString;
 use ::std::mem::transmute;
@@ -17,10 +17,15 @@ use self::ir::types::*;
 use self::ir::values::*;
 use self::machineCodeJit::*;
 use self::orcJit::*;
+use self::passes::*;
 use self::targets::*;
+use ::compiler::RandomFileNameGenerator;
+use ::compiler::TemporaryFolderPath;
 use ::libc::c_char;
 use ::libc::c_uint;
 use ::libc::c_void;
+use ::libc::dlsym;
+use ::libc::RTLD_DEFAULT;
 use ::llvm_sys::*;
 use ::llvm_sys::analysis::*;
 use ::llvm_sys::bit_reader::*;
@@ -31,40 +36,95 @@ use ::llvm_sys::execution_engine::LLVMInitializeMCJITCompilerOptions;
 use ::llvm_sys::execution_engine::LLVMLinkInMCJIT;
 use ::llvm_sys::execution_engine::LLVMMCJITCompilerOptions;
 use ::llvm_sys::ir_reader::LLVMParseIRInContext;
+use ::llvm_sys::linker::LLVMLinkModules2;
 use ::llvm_sys::object::*;
 use ::llvm_sys::orc::*;
 use ::llvm_sys::prelude::*;
 use ::llvm_sys::target::*;
 use ::llvm_sys::target_machine::*;
 use ::rust_extra::unlikely;
+use ::sha2::Digest;
+use ::sha2::Sha256;
+use ::std::borrow::Cow;
 use ::std::cell::RefCell;
 use ::std::collections::HashMap;
+use ::std::collections::HashSet;
+use ::std::error::Error;
 use ::std::ffi::CStr;
 use ::std::ffi::CString;
+use ::std::fmt;
+use ::std::fmt::Display;
+use ::std::fmt::Formatter;
+use ::std::fs::create_dir_all;
+use ::std::fs::File;
+use ::std::fs::read_dir;
+use ::std::fs::remove_file;
+use ::std::fs::rename;
+use ::std::hash::Hash;
+use ::std::hash::Hasher;
 use ::std::io;
 use ::std::io::Write;
+use ::std::marker::PhantomData;
+use ::std::mem::forget;
+use ::std::mem::replace;
 use ::std::mem::uninitialized;
 use ::std::mem::size_of;
 use ::std::mem::zeroed;
+use ::std::os::unix::ffi::OsStrExt;
+use ::std::path::Path;
+use ::std::path::PathBuf;
+use ::std::process::abort;
 use ::std::ptr::null;
 use ::std::ptr::null_mut;
 use ::std::rc::Rc;
 use ::std::slice::from_raw_parts;
+use ::std::sync::Arc;
+use ::std::sync::Mutex;
+use ::std::sync::MutexGuard;
+use ::std::sync::Once;
+use ::std::sync::ONCE_INIT;
+use ::std::time::SystemTime;
 
 
 #[macro_use] pub mod ir;
 pub mod machineCodeJit;
 pub mod orcJit;
+pub mod passes;
 pub mod targets;
+#[cfg(test)] mod tests;
 
 
+include!("ChainedSymbolResolver.rs");
+include!("CompilationMode.rs");
 include!("Context.rs");
 include!("ContextDropWrapper.rs");
+include!("Diagnostic.rs");
+include!("DynamicValue.rs");
+include!("DynamicValueKind.rs");
+include!("HashMapSymbolResolver.rs");
 include!("JitContext.rs");
+include!("LlvmError.rs");
+include!("LoadedModulesSymbolResolver.rs");
 include!("MemoryBuffer.rs");
 include!("MemoryBufferCreator.rs");
 include!("Module.rs");
 include!("ModuleDropWrapper.rs");
+include!("ModuleFunctions.rs");
+include!("ModuleGlobals.rs");
+include!("ModuleImport.rs");
+include!("ModuleImportKind.rs");
+include!("ModuleInternalisation.rs");
 include!("ModuleSourceCodeType.rs");
+include!("ObjectCache.rs");
+include!("ObjectCacheKey.rs");
+include!("ObjectCacheKeyHasher.rs");
+include!("PluginExport.rs");
+include!("PluginManifest.rs");
+include!("ProcessSymbolResolver.rs");
+include!("SandboxSymbolResolver.rs");
+include!("SharedJitContext.rs");
 include!("SuperContext.rs");
+include!("SymbolPattern.rs");
+include!("SymbolResolutionAttempt.rs");
+include!("SymbolResolutionOutcome.rs");
 include!("SymbolResolver.rs");
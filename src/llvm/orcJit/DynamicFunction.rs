@@ -0,0 +1,58 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A function of any arity whose signature is taken from the plugin's IR at runtime
+/// Calls go through a JIT'd trampoline, which unpacks each argument and calls the function using its own calling convention
+pub struct DynamicFunction<'a>
+{
+	#[allow(dead_code)] plugin: &'a ModuleInOrcJitStack,
+	trampoline: Rc<DynamicFunctionTrampoline>,
+}
+
+impl<'a> DynamicFunction<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(plugin: &'a ModuleInOrcJitStack, trampoline: Rc<DynamicFunctionTrampoline>) -> Self
+	{
+		Self
+		{
+			plugin: plugin,
+			trampoline: trampoline,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn returnKind(&self) -> DynamicValueKind
+	{
+		self.trampoline.returnKind()
+	}
+	
+	#[inline(always)]
+	pub fn parameterKinds(&self) -> &[DynamicValueKind]
+	{
+		self.trampoline.parameterKinds()
+	}
+	
+	/// Returns None if the function returns void
+	pub fn call(&self, arguments: &[DynamicValue]) -> Result<Option<DynamicValue>, LlvmError>
+	{
+		if unlikely(arguments.len() != self.parameterKinds().len())
+		{
+			return Err(LlvmError::dynamicArguments(format!("Expected {} arguments but was given {}", self.parameterKinds().len(), arguments.len())));
+		}
+		
+		let mut slots = Vec::with_capacity(arguments.len());
+		for (index, (argument, parameterKind)) in arguments.iter().zip(self.parameterKinds().iter()).enumerate()
+		{
+			if unlikely(argument.kind() != *parameterKind)
+			{
+				return Err(LlvmError::dynamicArguments(format!("Argument {} should be of kind {:?} but was {:?}", index, parameterKind, argument)));
+			}
+			slots.push(argument.toSlot());
+		}
+		
+		let result = unsafe { self.trampoline.call(&slots) };
+		Ok(self.returnKind().fromSlot(result))
+	}
+}
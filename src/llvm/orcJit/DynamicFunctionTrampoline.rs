@@ -0,0 +1,214 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// The JIT'd trampoline behind a `DynamicFunction`, `void trampoline(i64* arguments, i64* result)`, which unpacks each argument and calls the function using its own calling convention
+/// Trampolines are cached by the plugin they call (see `ModuleInOrcJitStack::dynamicFunction()`), and removed with it
+pub(crate) struct DynamicFunctionTrampoline
+{
+	#[allow(dead_code)] trampoline: ModuleInOrcJitStack,
+	trampolineFunctionPointer: TrampolineFunctionPointer,
+	returnKind: DynamicValueKind,
+	parameterKinds: Vec<DynamicValueKind>,
+}
+
+static TrampolineCounter: AtomicUsize = ATOMIC_USIZE_INIT;
+
+type TrampolineFunctionPointer = unsafe extern "C" fn(*const u64, *mut u64);
+
+impl DynamicFunctionTrampoline
+{
+	pub(crate) fn create(plugin: &ModuleInOrcJitStack, functionName: &str) -> Result<Self, LlvmError>
+	{
+		let module = match plugin.module
+		{
+			None => return Err(LlvmError::NoIntermediateRepresentation { functionName: functionName.to_owned() }),
+			Some(ref module) => module,
+		};
+		
+		let functionValue = match module.namedFunction(functionName)
+		{
+			None => return Err(LlvmError::FunctionNotFound { functionName: functionName.to_owned() }),
+			Some(functionValue) => functionValue,
+		};
+		
+		let functionType = functionValue.functionType();
+		if functionType.isFunctionVarArgs()
+		{
+			return Err(LlvmError::unsupportedType(format!("Function '{}' has variable arguments", functionName)));
+		}
+		
+		let returnKind = match DynamicValueKind::fromLLVMTypeRef(functionType.returnType())
+		{
+			None => return Err(LlvmError::unsupportedType(format!("Function '{}' has a return type '{}' that can not be returned dynamically", functionName, functionType.returnType().toString().to_string_lossy()))),
+			Some(returnKind) => returnKind,
+		};
+		
+		let parameterTypes = functionType.parameterTypes();
+		let mut parameterKinds = Vec::with_capacity(parameterTypes.len());
+		for parameterType in parameterTypes
+		{
+			match DynamicValueKind::fromLLVMTypeRef(parameterType)
+			{
+				None | Some(DynamicValueKind::Void) => return Err(LlvmError::unsupportedType(format!("Function '{}' has a parameter type '{}' that can not be passed dynamically", functionName, parameterType.toString().to_string_lossy()))),
+				Some(parameterKind) => parameterKinds.push(parameterKind),
+			}
+		}
+		
+		let functionAddress = plugin.getSymbolAddress(functionName);
+		if unlikely(functionAddress == 0)
+		{
+			return Err(LlvmError::FunctionHasNoAddress { functionName: functionName.to_owned() });
+		}
+		
+		let trampolineName = format!("predicator.dynamic.{}.{}", functionName, TrampolineCounter.fetch_add(1, Ordering::Relaxed));
+		let trampolineModule = module.createSiblingModule(&trampolineName)?;
+		Self::generateTrampoline(&trampolineModule, functionValue, functionAddress, &trampolineName, returnKind, &parameterKinds);
+		trampolineModule.verifyReference()?;
+		
+		let trampoline = ModuleInOrcJitStack
+		{
+			reference: CompilationMode::Eager.addToOrcJitStack(plugin.orcJitStackReference, trampolineModule.reference, Self::resolveNoSymbols, null_mut()),
+			orcJitStackReference: plugin.orcJitStackReference,
+			orcJitStackReferenceDropWrapper: plugin.orcJitStackReferenceDropWrapper.clone(),
+			module: Some(trampolineModule),
+			manifest: None,
+			dynamicFunctionTrampolines: RefCell::new(HashMap::new()),
+		};
+		
+		let trampolineAddress = trampoline.getSymbolAddress(&trampolineName);
+		if unlikely(trampolineAddress == 0)
+		{
+			return Err(LlvmError::FunctionHasNoAddress { functionName: trampolineName });
+		}
+		
+		Ok
+		(
+			Self
+			{
+				trampoline: trampoline,
+				trampolineFunctionPointer: unsafe { transmute(trampolineAddress as usize) },
+				returnKind: returnKind,
+				parameterKinds: parameterKinds,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn returnKind(&self) -> DynamicValueKind
+	{
+		self.returnKind
+	}
+	
+	#[inline(always)]
+	pub(crate) fn parameterKinds(&self) -> &[DynamicValueKind]
+	{
+		&self.parameterKinds
+	}
+	
+	/// `slots` must have one slot per parameter
+	#[inline(always)]
+	pub(crate) unsafe fn call(&self, slots: &[u64]) -> u64
+	{
+		let mut result = 0;
+		(self.trampolineFunctionPointer)(slots.as_ptr(), &mut result);
+		result
+	}
+	
+	/// The trampoline calls the plugin's function by its address, so has no symbols to resolve
+	extern "C" fn resolveNoSymbols(_symbolName: *const c_char, _lookupContext: *mut c_void) -> u64
+	{
+		SymbolNotFound
+	}
+	
+	/// The function is called through a constant pointer to `functionAddress`, rather than by name, so that a function of the same name in another plugin can not be called instead
+	fn generateTrampoline(trampolineModule: &Module, functionValue: FunctionValue, functionAddress: LLVMOrcTargetAddress, trampolineName: &str, returnKind: DynamicValueKind, parameterKinds: &[DynamicValueKind])
+	{
+		let moduleReference = trampolineModule.reference;
+		let contextReference = unsafe { LLVMGetModuleContext(moduleReference) };
+		let functionType = functionValue.functionType();
+		
+		let int32Type = unsafe { LLVMInt32TypeInContext(contextReference) };
+		let int64Type = unsafe { LLVMInt64TypeInContext(contextReference) };
+		
+		let callee = unsafe { LLVMConstIntToPtr(LLVMConstInt(int64Type, functionAddress, 0), LLVMPointerType(functionType.asLLVMTypeRef(), 0)) };
+		let callingConvention = unsafe { LLVMGetFunctionCallConv(functionValue.asLLVMValueRef()) };
+		let int64PointerType = unsafe { LLVMPointerType(int64Type, 0) };
+		let mut trampolineParameterTypes = [int64PointerType, int64PointerType];
+		let trampolineType = unsafe { LLVMFunctionType(LLVMVoidTypeInContext(contextReference), trampolineParameterTypes.as_mut_ptr(), 2, 0) };
+		
+		let cTrampolineName = CString::new(trampolineName).expect("trampolineName contains embedded NULs");
+		let trampoline = unsafe { LLVMAddFunction(moduleReference, cTrampolineName.as_ptr(), trampolineType) };
+		let argumentsPointer = unsafe { LLVMGetParam(trampoline, 0) };
+		let resultPointer = unsafe { LLVMGetParam(trampoline, 1) };
+		
+		let builder = unsafe { LLVMCreateBuilderInContext(contextReference) };
+		unsafe { LLVMPositionBuilderAtEnd(builder, LLVMAppendBasicBlockInContext(contextReference, trampoline, emptyName!())) };
+		
+		let parameterTypes = functionType.parameterTypes();
+		let mut arguments = Vec::with_capacity(parameterKinds.len());
+		for (index, (parameterKind, parameterType)) in parameterKinds.iter().zip(parameterTypes.iter()).enumerate()
+		{
+			let parameterType = parameterType.asLLVMTypeRef();
+			
+			let mut indices = [unsafe { LLVMConstInt(int64Type, index as u64, 0) }];
+			let slotPointer = unsafe { LLVMBuildInBoundsGEP(builder, argumentsPointer, indices.as_mut_ptr(), 1, emptyName!()) };
+			let slot = unsafe { LLVMBuildLoad(builder, slotPointer, emptyName!()) };
+			
+			use self::DynamicValueKind::*;
+			let argument = match *parameterKind
+			{
+				Void => unreachable!(),
+				Int1 | Int8 | Int16 | Int32 => unsafe { LLVMBuildTrunc(builder, slot, parameterType, emptyName!()) },
+				Int64 => slot,
+				Float32 => unsafe { LLVMBuildBitCast(builder, LLVMBuildTrunc(builder, slot, int32Type, emptyName!()), parameterType, emptyName!()) },
+				Float64 => unsafe { LLVMBuildBitCast(builder, slot, parameterType, emptyName!()) },
+				Pointer => unsafe { LLVMBuildIntToPtr(builder, slot, parameterType, emptyName!()) },
+			};
+			arguments.push(argument);
+		}
+		
+		let call = unsafe { LLVMBuildCall(builder, callee, arguments.as_mut_ptr(), arguments.len() as u32, emptyName!()) };
+		unsafe { LLVMSetInstructionCallConv(call, callingConvention) };
+		Self::copyExtensionAttributesToCallSite(functionValue, call, LLVMAttributeReturnIndex);
+		for parameterIndex in 0 .. parameterKinds.len()
+		{
+			Self::copyExtensionAttributesToCallSite(functionValue, call, parameterIndex as u32 + 1);
+		}
+		
+		{
+			use self::DynamicValueKind::*;
+			let result = match returnKind
+			{
+				Void => None,
+				Int1 | Int8 | Int16 | Int32 => Some(unsafe { LLVMBuildZExt(builder, call, int64Type, emptyName!()) }),
+				Int64 => Some(call),
+				Float32 => Some(unsafe { LLVMBuildZExt(builder, LLVMBuildBitCast(builder, call, int32Type, emptyName!()), int64Type, emptyName!()) }),
+				Float64 => Some(unsafe { LLVMBuildBitCast(builder, call, int64Type, emptyName!()) }),
+				Pointer => Some(unsafe { LLVMBuildPtrToInt(builder, call, int64Type, emptyName!()) }),
+			};
+			
+			if let Some(result) = result
+			{
+				unsafe { LLVMBuildStore(builder, result, resultPointer) };
+			}
+		}
+		
+		unsafe { LLVMBuildRetVoid(builder) };
+		unsafe { LLVMDisposeBuilder(builder) };
+	}
+	
+	/// Small integers are extended by the caller on some targets, so the call site must say how
+	#[inline(always)]
+	fn copyExtensionAttributesToCallSite(functionValue: FunctionValue, call: LLVMValueRef, attributeIndex: u32)
+	{
+		for enumAttributeName in [EnumAttributeName::zeroext, EnumAttributeName::signext, EnumAttributeName::inreg].iter()
+		{
+			let attribute = unsafe { LLVMGetEnumAttributeAtIndex(functionValue.asLLVMValueRef(), attributeIndex, enumAttributeName.identifier().0) };
+			if !attribute.is_null()
+			{
+				unsafe { LLVMAddCallSiteAttribute(call, attributeIndex, attribute) };
+			}
+		}
+	}
+}
@@ -0,0 +1,61 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// An `unsafe extern "C" fn` type whose signature can be checked against a function's LLVM IR signature
+pub trait ExternCFunctionPointer: Copy
+{
+	/// Always a `LlvmType::Function`
+	#[inline(always)]
+	fn signature() -> LlvmType;
+	
+	/// address must not be 0
+	#[inline(always)]
+	unsafe fn fromAddress(address: LLVMOrcTargetAddress) -> Self;
+	
+	/// eg to export a host function to plugins
+	#[inline(always)]
+	fn toAddress(self) -> LLVMOrcTargetAddress;
+}
+
+macro_rules! extern_c_function_pointer
+{
+	($($parameter: ident),*) =>
+	{
+		impl<R: ExternCType, $($parameter: ExternCType),*> ExternCFunctionPointer for unsafe extern "C" fn($($parameter),*) -> R
+		{
+			#[inline(always)]
+			fn signature() -> LlvmType
+			{
+				LlvmType::Function
+				{
+					returns: Box::new(R::llvmType()),
+					parameters: vec![$($parameter::llvmType()),*],
+					hasVarArgs: false,
+				}
+			}
+			
+			#[inline(always)]
+			unsafe fn fromAddress(address: LLVMOrcTargetAddress) -> Self
+			{
+				debug_assert!(address != 0, "address was 0");
+				
+				transmute(address as usize)
+			}
+			
+			#[inline(always)]
+			fn toAddress(self) -> LLVMOrcTargetAddress
+			{
+				self as usize as LLVMOrcTargetAddress
+			}
+		}
+	}
+}
+
+extern_c_function_pointer!();
+extern_c_function_pointer!(A);
+extern_c_function_pointer!(A, B);
+extern_c_function_pointer!(A, B, C);
+extern_c_function_pointer!(A, B, C, D);
+extern_c_function_pointer!(A, B, C, D, E);
+extern_c_function_pointer!(A, B, C, D, E, F);
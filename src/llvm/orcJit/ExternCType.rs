@@ -0,0 +1,62 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A Rust type that can cross the 'C' boundary into a plugin, and the LLVM type it is passed as
+/// Pointers are all treated as `i8*`, as the pointee type is ignored when comparing signatures
+pub trait ExternCType
+{
+	#[inline(always)]
+	fn llvmType() -> LlvmType;
+}
+
+macro_rules! extern_c_type
+{
+	($type: ty, $llvmType: expr) =>
+	{
+		impl ExternCType for $type
+		{
+			#[inline(always)]
+			fn llvmType() -> LlvmType
+			{
+				$llvmType
+			}
+		}
+	}
+}
+
+extern_c_type!((), LlvmType::Void);
+extern_c_type!(bool, LlvmType::Int1);
+extern_c_type!(u8, LlvmType::Int8);
+extern_c_type!(i8, LlvmType::Int8);
+extern_c_type!(u16, LlvmType::Int16);
+extern_c_type!(i16, LlvmType::Int16);
+extern_c_type!(u32, LlvmType::Int32);
+extern_c_type!(i32, LlvmType::Int32);
+extern_c_type!(u64, LlvmType::Int64);
+extern_c_type!(i64, LlvmType::Int64);
+extern_c_type!(f32, LlvmType::Float32);
+extern_c_type!(f64, LlvmType::Float64);
+
+#[cfg(target_pointer_width = "32")] extern_c_type!(usize, LlvmType::Int32);
+#[cfg(target_pointer_width = "32")] extern_c_type!(isize, LlvmType::Int32);
+#[cfg(target_pointer_width = "64")] extern_c_type!(usize, LlvmType::Int64);
+#[cfg(target_pointer_width = "64")] extern_c_type!(isize, LlvmType::Int64);
+
+impl<T> ExternCType for *const T
+{
+	#[inline(always)]
+	fn llvmType() -> LlvmType
+	{
+		LlvmType::int8Pointer()
+	}
+}
+
+impl<T> ExternCType for *mut T
+{
+	#[inline(always)]
+	fn llvmType() -> LlvmType
+	{
+		LlvmType::int8Pointer()
+	}
+}
@@ -0,0 +1,50 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A function pointer to an indirect stub of a `HotSwappablePlugin`; it borrows the plugin, so the plugin can not be dropped whilst this is alive
+/// Each `call()` holds on to the version the stubs point to when the call starts until it returns, so that a `swap()` made during the call (eg by the host, called back from the plugin) does not remove code that is still running
+/// As `HotSwappablePlugin` is not `Sync`, this can not be sent to another thread
+#[derive(Clone)]
+pub struct HotSwappableFunctionPointer<'a, F: Copy>
+{
+	functionPointer: F,
+	plugin: &'a HotSwappablePlugin,
+}
+
+impl<'a, F: Copy> HotSwappableFunctionPointer<'a, F>
+{
+	#[inline(always)]
+	pub(crate) fn new(functionPointer: F, plugin: &'a HotSwappablePlugin) -> Self
+	{
+		Self
+		{
+			functionPointer: functionPointer,
+			plugin: plugin,
+		}
+	}
+}
+
+macro_rules! hot_swappable_function_pointer_call
+{
+	($($parameter: ident: $parameterType: ident),*) =>
+	{
+		impl<'a, R, $($parameterType),*> HotSwappableFunctionPointer<'a, unsafe extern "C" fn($($parameterType),*) -> R>
+		{
+			#[inline(always)]
+			pub unsafe fn call(&self, $($parameter: $parameterType),*) -> R
+			{
+				let _versionBeingCalled = self.plugin.currentVersion();
+				(self.functionPointer)($($parameter),*)
+			}
+		}
+	}
+}
+
+hot_swappable_function_pointer_call!();
+hot_swappable_function_pointer_call!(a: A);
+hot_swappable_function_pointer_call!(a: A, b: B);
+hot_swappable_function_pointer_call!(a: A, b: B, c: C);
+hot_swappable_function_pointer_call!(a: A, b: B, c: C, d: D);
+hot_swappable_function_pointer_call!(a: A, b: B, c: C, d: D, e: E);
+hot_swappable_function_pointer_call!(a: A, b: B, c: C, d: D, e: E, f: F);
@@ -0,0 +1,161 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Hands out stable function addresses, via ORC indirect stubs, for functions in a plugin that can be replaced whilst the process is running
+/// Function pointers obtained from `functionPointer()` remain valid across calls to `swap()`, and borrow this so it can not be dropped whilst they are in use
+/// A version replaced by `swap()` is removed from the JIT stack once no call made through a function pointer is still executing it, and nothing holds it from `currentVersion()`
+/// Only calls made through `HotSwappableFunctionPointer::call()` are tracked; nothing else should call the stubs or the versions' functions directly
+/// Stubs can not be removed from an ORC JIT stack, so each `HotSwappablePlugin` permanently uses a few bytes of the stack's stub memory
+pub struct HotSwappablePlugin
+{
+	orcJitStackReference: LLVMOrcJITStackRef,
+	#[allow(dead_code)] orcJitStackReferenceDropWrapper: Rc<OrcJitStackDropWrapper>,
+	current: RefCell<Rc<ModuleInOrcJitStack>>,
+	stubs: HashMap<String, IndirectStub>,
+}
+
+static HotSwappablePluginCounter: AtomicUsize = ATOMIC_USIZE_INIT;
+
+impl HotSwappablePlugin
+{
+	/// `functionNames` are the functions that can be called through stubs; a replacement must define all of them with the same signatures
+	/// The plugin must have been loaded from IR (not an object file) so that signatures can be checked
+	pub fn new(plugin: ModuleInOrcJitStack, functionNames: &[&str]) -> Result<Self, LlvmError>
+	{
+		let orcJitStackReference = plugin.orcJitStackReference;
+		let identifier = HotSwappablePluginCounter.fetch_add(1, Ordering::Relaxed);
+		
+		let mut stubs = HashMap::with_capacity(functionNames.len());
+		for functionName in functionNames.iter()
+		{
+			let signature = Self::functionSignature(&plugin, functionName)?;
+			
+			let address = plugin.getSymbolAddress(functionName);
+			if unlikely(address == 0)
+			{
+				return Err(LlvmError::FunctionHasNoAddress { functionName: functionName.to_string() });
+			}
+			
+			// Stub names must not be the same as the function names, otherwise the stub would be found instead of a replacement's function
+			let stubName = format!("predicator.hotswap.{}.{}", identifier, functionName);
+			let stub = IndirectStub::create(orcJitStackReference, stubName, signature, address)?;
+			stubs.insert(functionName.to_string(), stub);
+		}
+		
+		Ok
+		(
+			Self
+			{
+				orcJitStackReference: orcJitStackReference,
+				orcJitStackReferenceDropWrapper: plugin.orcJitStackReferenceDropWrapper.clone(),
+				current: RefCell::new(Rc::new(plugin)),
+				stubs: stubs,
+			}
+		)
+	}
+	
+	/// Returns the address of the stub for `functionName`, not of the function itself, so it continues to call the latest version after a `swap()`
+	pub fn functionPointer<'a, F: ExternCFunctionPointer>(&'a self, functionName: &str) -> Result<HotSwappableFunctionPointer<'a, F>, LlvmError>
+	{
+		let stub = match self.stubs.get(functionName)
+		{
+			None => return Err(LlvmError::FunctionNotFound { functionName: functionName.to_owned() }),
+			Some(stub) => stub,
+		};
+		
+		self.current.borrow().checkFunctionSignature(functionName, &F::signature())?;
+		
+		let address = stub.address(self.orcJitStackReference);
+		if unlikely(address == 0)
+		{
+			Err(LlvmError::FunctionHasNoAddress { functionName: functionName.to_owned() })
+		}
+		else
+		{
+			Ok(HotSwappableFunctionPointer::new(unsafe { F::fromAddress(address) }, self))
+		}
+	}
+	
+	/// The version that stubs currently point to
+	/// Holding on to this keeps the version in the JIT stack after it has been replaced by `swap()`
+	#[inline(always)]
+	pub fn currentVersion(&self) -> Rc<ModuleInOrcJitStack>
+	{
+		self.current.borrow().clone()
+	}
+	
+	/// Every function is checked in `replacement`, and its address found, before any stub is repointed; if repointing a stub fails, those already repointed are pointed back at the current version, so on error the current version remains in use
+	/// Each stub is repointed atomically, but a caller using several functions may briefly see a mixture of versions
+	/// The previous version is removed from the JIT stack when this returns, unless a call is still executing it (eg this is being called from a host function the plugin called), in which case it is removed once the last such call returns
+	pub fn swap(&self, replacement: ModuleInOrcJitStack) -> Result<(), LlvmError>
+	{
+		if unlikely(replacement.orcJitStackReference != self.orcJitStackReference)
+		{
+			return Err(LlvmError::DifferentJitContext);
+		}
+		
+		{
+			let current = self.current.borrow();
+			
+			let mut addresses = Vec::with_capacity(self.stubs.len());
+			for (functionName, stub) in self.stubs.iter()
+			{
+				replacement.checkFunctionSignature(functionName, stub.signature())?;
+				
+				let newAddress = replacement.getSymbolAddress(functionName);
+				if unlikely(newAddress == 0)
+				{
+					return Err(LlvmError::FunctionHasNoAddress { functionName: functionName.to_owned() });
+				}
+				
+				let currentAddress = current.getSymbolAddress(functionName);
+				if unlikely(currentAddress == 0)
+				{
+					return Err(LlvmError::FunctionHasNoAddress { functionName: functionName.to_owned() });
+				}
+				
+				addresses.push((stub, currentAddress, newAddress));
+			}
+			
+			for (index, &(stub, _, newAddress)) in addresses.iter().enumerate()
+			{
+				if let Err(error) = stub.repoint(self.orcJitStackReference, newAddress)
+				{
+					// Best efforts; repointing back to an address a stub has already held is not expected to fail
+					for &(stub, currentAddress, _) in addresses[.. index].iter()
+					{
+						let _ = stub.repoint(self.orcJitStackReference, currentAddress);
+					}
+					return Err(error);
+				}
+			}
+		}
+		
+		// Dropped when this returns, removing the previous version unless a call is still executing it
+		let _previous = replace(&mut *self.current.borrow_mut(), Rc::new(replacement));
+		Ok(())
+	}
+	
+	fn functionSignature(plugin: &ModuleInOrcJitStack, functionName: &str) -> Result<LlvmType, LlvmError>
+	{
+		let module = match plugin.module
+		{
+			None => return Err(LlvmError::NoIntermediateRepresentation { functionName: functionName.to_owned() }),
+			Some(ref module) => module,
+		};
+		
+		match module.namedFunction(functionName)
+		{
+			None => Err(LlvmError::FunctionNotFound { functionName: functionName.to_owned() }),
+			Some(functionValue) => if functionValue.isDeclaration()
+			{
+				Err(LlvmError::FunctionNotDefined { functionName: functionName.to_owned() })
+			}
+			else
+			{
+				LlvmType::fromLLVMTypeRef(functionValue.functionType())
+			},
+		}
+	}
+}
@@ -0,0 +1,69 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+pub(crate) struct IndirectStub
+{
+	stubName: CString,
+	signature: LlvmType,
+}
+
+impl IndirectStub
+{
+	#[inline(always)]
+	pub(crate) fn create(orcJitStackReference: LLVMOrcJITStackRef, stubName: String, signature: LlvmType, initialAddress: LLVMOrcTargetAddress) -> Result<Self, LlvmError>
+	{
+		let stubName = CString::new(stubName).expect("Contains embedded NULs");
+		
+		match unsafe { LLVMOrcCreateIndirectStub(orcJitStackReference, stubName.as_ptr(), initialAddress) }
+		{
+			LLVMOrcErrorCode::LLVMOrcErrSuccess => Ok
+			(
+				Self
+				{
+					stubName: stubName,
+					signature: signature,
+				}
+			),
+			LLVMOrcErrorCode::LLVMOrcErrGeneric => Err(LlvmError::IndirectStub { stubName: stubName.to_string_lossy().into_owned(), message: Self::errorMessage(orcJitStackReference) }),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn signature(&self) -> &LlvmType
+	{
+		&self.signature
+	}
+	
+	/// 0 is not found
+	#[inline(always)]
+	pub(crate) fn address(&self, orcJitStackReference: LLVMOrcJITStackRef) -> LLVMOrcTargetAddress
+	{
+		unsafe { LLVMOrcGetSymbolAddress(orcJitStackReference, self.stubName.as_ptr()) }
+	}
+	
+	/// The stub's pointer is a single pointer-sized write, so callers see either the old or the new address
+	#[inline(always)]
+	pub(crate) fn repoint(&self, orcJitStackReference: LLVMOrcJITStackRef, newAddress: LLVMOrcTargetAddress) -> Result<(), LlvmError>
+	{
+		match unsafe { LLVMOrcSetIndirectStubPointer(orcJitStackReference, self.stubName.as_ptr(), newAddress) }
+		{
+			LLVMOrcErrorCode::LLVMOrcErrSuccess => Ok(()),
+			LLVMOrcErrorCode::LLVMOrcErrGeneric => Err(LlvmError::IndirectStub { stubName: self.stubName.to_string_lossy().into_owned(), message: Self::errorMessage(orcJitStackReference) }),
+		}
+	}
+	
+	#[inline(always)]
+	fn errorMessage(orcJitStackReference: LLVMOrcJITStackRef) -> String
+	{
+		let message = unsafe { LLVMOrcGetErrorMsg(orcJitStackReference) };
+		if unlikely(message.is_null())
+		{
+			String::new()
+		}
+		else
+		{
+			unsafe { CStr::from_ptr(message) }.to_string_lossy().into_owned()
+		}
+	}
+}
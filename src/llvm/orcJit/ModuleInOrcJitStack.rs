@@ -7,6 +7,15 @@ pub struct ModuleInOrcJitStack
 	pub(crate) reference: LLVMOrcModuleHandle,
 	pub(crate) orcJitStackReference: LLVMOrcJITStackRef,
 	#[allow(dead_code)] pub(crate) orcJitStackReferenceDropWrapper: Rc<OrcJitStackDropWrapper>,
+	
+	/// None if loaded from an object file, in which case there is no IR to check function signatures against
+	pub(crate) module: Option<Module>,
+	
+	/// Only present if loaded with `JitContext::loadPlugins()`
+	pub(crate) manifest: Option<PluginManifest>,
+	
+	/// Keyed by function name; see `dynamicFunction()`
+	pub(crate) dynamicFunctionTrampolines: RefCell<HashMap<String, Rc<DynamicFunctionTrampoline>>>,
 }
 
 impl Drop for ModuleInOrcJitStack
@@ -31,7 +40,7 @@ macro_rules! function_pointer
 			else
 			{
 				let functionPointer: $functionType = unsafe { transmute(address as usize) };
-				Some(functionPointer)
+				Some(PluginFunctionPointer::new(functionPointer))
 			}
 		}
 	}
@@ -46,21 +55,28 @@ extern "C"
 impl ModuleInOrcJitStack
 {
 	#[inline(always)]
-	pub fn globalValuePointerNullable<T: Sized>(&self, staticName: &str) -> *mut T
+	pub fn manifest(&self) -> Option<&PluginManifest>
+	{
+		self.manifest.as_ref()
+	}
+	
+	/// The returned pointer is null if there is no such global
+	#[inline(always)]
+	pub fn globalValuePointerNullable<'a, T: Sized>(&'a self, staticName: &str) -> PluginGlobal<'a, T>
 	{
 		let address = self.getSymbolAddress(staticName);
 		if unlikely(address == 0)
 		{
-			null_mut()
+			PluginGlobal::new(null_mut())
 		}
 		else
 		{
-			unsafe { transmute(address as usize) }
+			PluginGlobal::new(unsafe { transmute(address as usize) })
 		}
 	}
 	
 	#[inline(always)]
-	pub fn globalValuePointerDefensive<T: Sized>(&self, staticName: &str) -> Option<&mut T>
+	pub fn globalValuePointerDefensive<'a, T: Sized>(&'a self, staticName: &str) -> Option<PluginGlobal<'a, T>>
 	{
 		let address = self.getSymbolAddress(staticName);
 		if unlikely(address == 0)
@@ -69,48 +85,121 @@ impl ModuleInOrcJitStack
 		}
 		else
 		{
-			Some(unsafe { transmute(address as usize) })
+			Some(PluginGlobal::new(unsafe { transmute(address as usize) }))
+		}
+	}
+	
+	/// Checks the requested function pointer type against the function's signature in the plugin's IR before handing out a function pointer
+	/// eg `plugins.functionPointer::<unsafe extern "C" fn(u32, *const u8) -> bool>("my_filter")`
+	pub fn functionPointer<'a, F: ExternCFunctionPointer>(&'a self, functionName: &str) -> Result<PluginFunctionPointer<'a, F>, LlvmError>
+	{
+		self.checkFunctionSignature(functionName, &F::signature())?;
+		
+		let address = self.getSymbolAddress(functionName);
+		if unlikely(address == 0)
+		{
+			Err(LlvmError::FunctionHasNoAddress { functionName: functionName.to_owned() })
+		}
+		else
+		{
+			Ok(PluginFunctionPointer::new(unsafe { F::fromAddress(address) }))
 		}
 	}
 	
+	/// Checks that a function with the expected signature (a `LlvmType::Function`) is defined in the plugin's IR
+	pub fn checkFunctionSignature(&self, functionName: &str, expectedSignature: &LlvmType) -> Result<(), LlvmError>
+	{
+		let module = match self.module
+		{
+			None => return Err(LlvmError::NoIntermediateRepresentation { functionName: functionName.to_owned() }),
+			Some(ref module) => module,
+		};
+		
+		let functionValue = match module.namedFunction(functionName)
+		{
+			None => return Err(LlvmError::FunctionNotFound { functionName: functionName.to_owned() }),
+			Some(functionValue) => functionValue,
+		};
+		
+		if functionValue.isDeclaration()
+		{
+			return Err(LlvmError::FunctionNotDefined { functionName: functionName.to_owned() });
+		}
+		
+		let actualSignature = functionValue.functionType();
+		if actualSignature.isCompatibleWith(expectedSignature)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(LlvmError::functionSignatureMismatch(functionName, expectedSignature, actualSignature))
+		}
+	}
+	
+	/// For functions of any arity, or whose signature is only known at runtime
+	/// The trampoline for a function is JIT'd the first time this is called for it, and is then re-used until this plugin is dropped
+	pub fn dynamicFunction<'a>(&'a self, functionName: &str) -> Result<DynamicFunction<'a>, LlvmError>
+	{
+		if let Some(trampoline) = self.dynamicFunctionTrampolines.borrow().get(functionName)
+		{
+			return Ok(DynamicFunction::new(self, trampoline.clone()));
+		}
+		
+		let trampoline = Rc::new(DynamicFunctionTrampoline::create(self, functionName)?);
+		self.dynamicFunctionTrampolines.borrow_mut().insert(functionName.to_owned(), trampoline.clone());
+		Ok(DynamicFunction::new(self, trampoline))
+	}
+	
+	/// Convenience for a one-off call; uses the same cached trampoline as `dynamicFunction()`
+	pub fn callDynamically(&self, functionName: &str, returnKind: DynamicValueKind, arguments: &[DynamicValue]) -> Result<Option<DynamicValue>, LlvmError>
+	{
+		let dynamicFunction = self.dynamicFunction(functionName)?;
+		if unlikely(dynamicFunction.returnKind() != returnKind)
+		{
+			return Err(LlvmError::dynamicArguments(format!("Function '{}' returns {:?} but {:?} was requested", functionName, dynamicFunction.returnKind(), returnKind)));
+		}
+		dynamicFunction.call(arguments)
+	}
+	
 	#[inline(always)]
-	pub fn nullaryFunctionPointer<R>(&self, functionName: &str) -> Option<unsafe extern "C" fn() -> R>
+	pub fn nullaryFunctionPointer<'a, R>(&'a self, functionName: &str) -> Option<PluginFunctionPointer<'a, unsafe extern "C" fn() -> R>>
 	{
 		function_pointer!(self, functionName, unsafe extern "C" fn() -> R)
 	}
 	
 	#[inline(always)]
-	pub fn unaryFunctionPointer<R, A>(&self, functionName: &str) -> Option<unsafe extern "C" fn(A) -> R>
+	pub fn unaryFunctionPointer<'a, R, A>(&'a self, functionName: &str) -> Option<PluginFunctionPointer<'a, unsafe extern "C" fn(A) -> R>>
 	{
 		function_pointer!(self, functionName, unsafe extern "C" fn(a: A) -> R)
 	}
 	
 	#[inline(always)]
-	pub fn binaryFunctionPointer<R, A, B>(&self, functionName: &str) -> Option<unsafe extern "C" fn(A, B) -> R>
+	pub fn binaryFunctionPointer<'a, R, A, B>(&'a self, functionName: &str) -> Option<PluginFunctionPointer<'a, unsafe extern "C" fn(A, B) -> R>>
 	{
 		function_pointer!(self, functionName, unsafe extern "C" fn(a: A, b: B) -> R)
 	}
 	
 	#[inline(always)]
-	pub fn ternaryFunctionPointer<R, A, B, C>(&self, functionName: &str) -> Option<unsafe extern "C" fn(A, B, C) -> R>
+	pub fn ternaryFunctionPointer<'a, R, A, B, C>(&'a self, functionName: &str) -> Option<PluginFunctionPointer<'a, unsafe extern "C" fn(A, B, C) -> R>>
 	{
 		function_pointer!(self, functionName, unsafe extern "C" fn(a: A, b: B, c: C) -> R)
 	}
 	
 	#[inline(always)]
-	pub fn quaternaryFunctionPointer<R, A, B, C, D>(&self, functionName: &str) -> Option<unsafe extern "C" fn(A, B, C, D) -> R>
+	pub fn quaternaryFunctionPointer<'a, R, A, B, C, D>(&'a self, functionName: &str) -> Option<PluginFunctionPointer<'a, unsafe extern "C" fn(A, B, C, D) -> R>>
 	{
 		function_pointer!(self, functionName, unsafe extern "C" fn(a: A, b: B, c: C, d: D) -> R)
 	}
 	
 	#[inline(always)]
-	pub fn quinaryFunctionPointer<R, A, B, C, D, E>(&self, functionName: &str) -> Option<unsafe extern "C" fn(A, B, C, D, E) -> R>
+	pub fn quinaryFunctionPointer<'a, R, A, B, C, D, E>(&'a self, functionName: &str) -> Option<PluginFunctionPointer<'a, unsafe extern "C" fn(A, B, C, D, E) -> R>>
 	{
 		function_pointer!(self, functionName, unsafe extern "C" fn(a: A, b: B, c: C, d: D, e: E) -> R)
 	}
 	
 	#[inline(always)]
-	pub fn senaryFunctionPointer<R, A, B, C, D, E, F>(&self, functionName: &str) -> Option<unsafe extern "C" fn(A, B, C, D, E, F) -> R>
+	pub fn senaryFunctionPointer<'a, R, A, B, C, D, E, F>(&'a self, functionName: &str) -> Option<PluginFunctionPointer<'a, unsafe extern "C" fn(A, B, C, D, E, F) -> R>>
 	{
 		function_pointer!(self, functionName, unsafe extern "C" fn(a: A, b: B, c: C, d: D, e: E, f: F) -> R)
 	}
@@ -121,6 +210,27 @@ impl ModuleInOrcJitStack
 	{
 		let symbolNameCString = CString::new(symbolName).expect("Contains embedded NULs");
 		
-		unsafe { LLVMOrcGetSymbolAddress(self.orcJitStackReference, symbolNameCString.as_ptr()) }
+		self.symbolAddress(&symbolNameCString)
+	}
+	
+	/// Symbols used by a plugin are only resolved when it is finalized, which happens on the first lookup of any of its symbols; looking up its exports forces this
+	/// Does nothing for plugins without a manifest, or for lazily compiled plugins, whose functions are finalized as they are first called
+	#[inline(always)]
+	pub(crate) fn finalizeExports(&self)
+	{
+		if let Some(ref manifest) = self.manifest
+		{
+			for export in manifest.exports.iter()
+			{
+				self.getSymbolAddress(&export.functionName);
+			}
+		}
+	}
+	
+	/// 0 is not found; `symbolName` is not mangled
+	#[inline(always)]
+	pub(crate) fn symbolAddress(&self, symbolName: &CStr) -> LLVMOrcTargetAddress
+	{
+		unsafe { LLVMOrcGetSymbolAddress(self.orcJitStackReference, symbolName.as_ptr()) }
 	}
 }
@@ -18,13 +18,23 @@ impl Drop for ObjectFile
 
 impl ObjectFile
 {
+	/// `memoryBuffer` is copied, as LLVMCreateObjectFile takes ownership of the buffer it is given (even if it fails)
 	#[inline(always)]
-	pub fn create(memoryBuffer: &MemoryBuffer) -> Result<Self, ()>
+	pub fn create(memoryBuffer: &MemoryBuffer) -> Result<Self, LlvmError>
 	{
-		let reference = unsafe { LLVMCreateObjectFile(memoryBuffer.reference) };
+		// "a\0"
+		static BufferName: [i8; 2] = [65, 0];
+		
+		let copyReference = unsafe { LLVMCreateMemoryBufferWithMemoryRangeCopy(memoryBuffer.address(), memoryBuffer.size(), BufferName.as_ptr()) };
+		if unlikely(copyReference.is_null())
+		{
+			return Err(LlvmError::CouldNotCreate("copy of memory buffer"));
+		}
+		
+		let reference = unsafe { LLVMCreateObjectFile(copyReference) };
 		if reference.is_null()
 		{
-			Err(())
+			Err(LlvmError::InvalidObjectFile)
 		}
 		else
 		{
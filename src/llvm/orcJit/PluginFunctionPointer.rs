@@ -0,0 +1,48 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A function pointer that borrows the plugin it came from, so the plugin can not be dropped whilst this is alive
+/// Call it with `call()`, eg `unsafe { function.call(1, 2) }`; the function pointer itself is never handed out, so can not outlive the borrow
+#[derive(Debug, Clone)]
+pub struct PluginFunctionPointer<'a, F: Copy>
+{
+	functionPointer: F,
+	lifetime: PhantomData<&'a ()>,
+}
+
+impl<'a, F: Copy> PluginFunctionPointer<'a, F>
+{
+	#[inline(always)]
+	pub(crate) fn new(functionPointer: F) -> Self
+	{
+		Self
+		{
+			functionPointer: functionPointer,
+			lifetime: PhantomData,
+		}
+	}
+}
+
+macro_rules! plugin_function_pointer_call
+{
+	($($parameter: ident: $parameterType: ident),*) =>
+	{
+		impl<'a, R, $($parameterType),*> PluginFunctionPointer<'a, unsafe extern "C" fn($($parameterType),*) -> R>
+		{
+			#[inline(always)]
+			pub unsafe fn call(&self, $($parameter: $parameterType),*) -> R
+			{
+				(self.functionPointer)($($parameter),*)
+			}
+		}
+	}
+}
+
+plugin_function_pointer_call!();
+plugin_function_pointer_call!(a: A);
+plugin_function_pointer_call!(a: A, b: B);
+plugin_function_pointer_call!(a: A, b: B, c: C);
+plugin_function_pointer_call!(a: A, b: B, c: C, d: D);
+plugin_function_pointer_call!(a: A, b: B, c: C, d: D, e: E);
+plugin_function_pointer_call!(a: A, b: B, c: C, d: D, e: E, f: F);
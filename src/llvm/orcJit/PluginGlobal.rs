@@ -0,0 +1,54 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A pointer to a global static in a plugin that borrows the plugin, so the plugin can not be dropped whilst this is alive
+/// Accessing the value is unsafe as the plugin's own code may read or write it at any time
+#[derive(Debug)]
+pub struct PluginGlobal<'a, T: 'a + Sized>
+{
+	pointer: *mut T,
+	lifetime: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a + Sized> PluginGlobal<'a, T>
+{
+	#[inline(always)]
+	pub(crate) fn new(pointer: *mut T) -> Self
+	{
+		Self
+		{
+			pointer: pointer,
+			lifetime: PhantomData,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn isNull(&self) -> bool
+	{
+		self.pointer.is_null()
+	}
+	
+	/// Only valid whilst this is alive
+	#[inline(always)]
+	pub fn pointer(&self) -> *mut T
+	{
+		self.pointer
+	}
+	
+	#[inline(always)]
+	pub unsafe fn asRef(&self) -> &T
+	{
+		debug_assert!(!self.isNull(), "Global is null");
+		
+		&*self.pointer
+	}
+	
+	#[inline(always)]
+	pub unsafe fn asMut(&mut self) -> &mut T
+	{
+		debug_assert!(!self.isNull(), "Global is null");
+		
+		&mut *self.pointer
+	}
+}
@@ -0,0 +1,85 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A plugin loaded with `SharedJitContext::loadPlugins()`; can be sent to, and shared between, threads
+/// Only functions exported in the plugin's manifest are available, as the plugin's IR (which belongs to a thread's `Context`) is not kept
+pub struct SharedModuleInOrcJitStack
+{
+	reference: LLVMOrcModuleHandle,
+	orcJitStack: Arc<SharedOrcJitStack>,
+	#[allow(dead_code)] symbolResolver: Arc<SymbolResolver + Send + Sync>,
+	manifest: PluginManifest,
+}
+
+impl Drop for SharedModuleInOrcJitStack
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		let reference = self.reference;
+		self.orcJitStack.locked(|orcJitStackReference| unsafe { LLVMOrcRemoveModule(orcJitStackReference, reference) })
+	}
+}
+
+impl SharedModuleInOrcJitStack
+{
+	#[inline(always)]
+	pub(crate) fn new(reference: LLVMOrcModuleHandle, orcJitStack: Arc<SharedOrcJitStack>, symbolResolver: Arc<SymbolResolver + Send + Sync>, manifest: PluginManifest) -> Self
+	{
+		Self
+		{
+			reference: reference,
+			orcJitStack: orcJitStack,
+			symbolResolver: symbolResolver,
+			manifest: manifest,
+		}
+	}
+	
+	/// See `ModuleInOrcJitStack::finalizeExports()`
+	/// The caller must hold the lock on the ORC JIT stack (see `SharedJitContext::loadPlugins()`), so that the forbidden symbols of a plugin being loaded on another thread are not mixed up with these
+	#[inline(always)]
+	pub(crate) fn finalizeExportsAndRejectForbiddenSymbols<SR: SymbolResolver + ?Sized>(&self, orcJitStackReference: LLVMOrcJITStackRef, symbolResolver: &SR) -> Result<(), LlvmError>
+	{
+		for export in self.manifest.exports.iter()
+		{
+			let functionNameCString = CString::new(export.functionName.as_str()).expect("Export names are checked for NULs when the manifest is read");
+			unsafe { LLVMOrcGetSymbolAddress(orcJitStackReference, functionNameCString.as_ptr()) };
+		}
+		
+		rejectForbiddenSymbols(symbolResolver)
+	}
+	
+	#[inline(always)]
+	pub fn manifest(&self) -> &PluginManifest
+	{
+		&self.manifest
+	}
+	
+	/// Checks the requested function pointer type against the function's signature in the plugin's manifest before handing out a function pointer
+	pub fn functionPointer<'a, F: ExternCFunctionPointer>(&'a self, functionName: &str) -> Result<PluginFunctionPointer<'a, F>, LlvmError>
+	{
+		let export = match self.manifest.exports.iter().find(|export| export.functionName == functionName)
+		{
+			None => return Err(LlvmError::FunctionNotFound { functionName: functionName.to_owned() }),
+			Some(export) => export,
+		};
+		
+		let expectedSignature = F::signature();
+		if unlikely(!export.signature.isCompatibleWith(&expectedSignature))
+		{
+			return Err(LlvmError::FunctionSignatureMismatch { functionName: functionName.to_owned(), expected: expectedSignature.to_string(), actual: export.signature.to_string() });
+		}
+		
+		let functionNameCString = CString::new(functionName).expect("Contains embedded NULs");
+		let address = self.orcJitStack.locked(|orcJitStackReference| unsafe { LLVMOrcGetSymbolAddress(orcJitStackReference, functionNameCString.as_ptr()) });
+		if unlikely(address == 0)
+		{
+			Err(LlvmError::FunctionHasNoAddress { functionName: functionName.to_owned() })
+		}
+		else
+		{
+			Ok(PluginFunctionPointer::new(unsafe { F::fromAddress(address) }))
+		}
+	}
+}
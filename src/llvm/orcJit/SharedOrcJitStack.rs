@@ -0,0 +1,50 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// An ORC JIT stack that can be shared between threads
+/// ORC itself is not thread safe, so every call into it is made whilst holding a lock; calling JIT'd code does not need the lock
+pub(crate) struct SharedOrcJitStack
+{
+	reference: LLVMOrcJITStackRef,
+	lock: Mutex<()>,
+}
+
+unsafe impl Send for SharedOrcJitStack
+{
+}
+
+unsafe impl Sync for SharedOrcJitStack
+{
+}
+
+impl Drop for SharedOrcJitStack
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		unsafe { LLVMOrcDisposeInstance(self.reference) }
+	}
+}
+
+impl SharedOrcJitStack
+{
+	#[inline(always)]
+	pub(crate) fn new(reference: LLVMOrcJITStackRef) -> Self
+	{
+		Self
+		{
+			reference: reference,
+			lock: Mutex::new(()),
+		}
+	}
+	
+	/// Symbol resolvers are called whilst the lock is held (when a module is added, or when it is finalized on first symbol lookup), so they must not call back into the same stack
+	#[inline(always)]
+	pub(crate) fn locked<R, F: FnOnce(LLVMOrcJITStackRef) -> R>(&self, callback: F) -> R
+	{
+		// A panic whilst holding the lock can not have left ORC in a worse state than a panic in any other caller, so poisoning is ignored
+		let _guard = self.lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		callback(self.reference)
+	}
+}
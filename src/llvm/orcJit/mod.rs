@@ -4,11 +4,31 @@
 
 use super::*;
 use ::rust_extra::unlikely;
+use ::std::cell::RefCell;
+use ::std::collections::HashMap;
+use ::std::marker::PhantomData;
+use ::std::mem::replace;
 use ::std::mem::transmute;
 use ::std::ptr::null_mut;
 use ::std::rc::Rc;
+use ::std::sync::Arc;
+use ::std::sync::Mutex;
+use ::std::sync::atomic::ATOMIC_USIZE_INIT;
+use ::std::sync::atomic::AtomicUsize;
+use ::std::sync::atomic::Ordering;
 
 
+include!("DynamicFunction.rs");
+include!("DynamicFunctionTrampoline.rs");
+include!("ExternCFunctionPointer.rs");
+include!("ExternCType.rs");
+include!("HotSwappableFunctionPointer.rs");
+include!("HotSwappablePlugin.rs");
+include!("IndirectStub.rs");
 include!("ModuleInOrcJitStack.rs");
 include!("ObjectFile.rs");
 include!("OrcJitStackDropWrapper.rs");
+include!("PluginFunctionPointer.rs");
+include!("PluginGlobal.rs");
+include!("SharedModuleInOrcJitStack.rs");
+include!("SharedOrcJitStack.rs");
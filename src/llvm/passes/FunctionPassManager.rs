@@ -0,0 +1,64 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Runs function passes over each function in one module
+pub struct FunctionPassManager
+{
+	reference: LLVMPassManagerRef,
+	module: Module,
+}
+
+impl Drop for FunctionPassManager
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		unsafe { LLVMDisposePassManager(self.reference) }
+	}
+}
+
+impl FunctionPassManager
+{
+	#[inline(always)]
+	pub fn new(module: &Module) -> Result<Self, LlvmError>
+	{
+		let reference = unsafe { LLVMCreateFunctionPassManagerForModule(module.reference) };
+		if unlikely(reference.is_null())
+		{
+			Err(LlvmError::CouldNotCreate("function pass manager"))
+		}
+		else
+		{
+			Ok
+			(
+				Self
+				{
+					reference: reference,
+					module: module.clone(),
+				}
+			)
+		}
+	}
+	
+	#[inline(always)]
+	pub fn addPass(&self, namedPass: NamedPass)
+	{
+		namedPass.addTo(self.reference)
+	}
+	
+	/// Returns true if any function was modified
+	pub fn run(&self) -> bool
+	{
+		let mut modified = unsafe { LLVMInitializeFunctionPassManager(self.reference) } != 0;
+		
+		for functionValue in self.module.functions()
+		{
+			modified |= unsafe { LLVMRunFunctionPassManager(self.reference, functionValue.asLLVMValueRef()) } != 0;
+		}
+		
+		modified |= unsafe { LLVMFinalizeFunctionPassManager(self.reference) } != 0;
+		
+		modified
+	}
+}
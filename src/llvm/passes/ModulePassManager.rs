@@ -0,0 +1,54 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+pub struct ModulePassManager
+{
+	reference: LLVMPassManagerRef,
+}
+
+impl Drop for ModulePassManager
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		unsafe { LLVMDisposePassManager(self.reference) }
+	}
+}
+
+impl ModulePassManager
+{
+	#[inline(always)]
+	pub fn new() -> Result<Self, LlvmError>
+	{
+		let reference = unsafe { LLVMCreatePassManager() };
+		if unlikely(reference.is_null())
+		{
+			Err(LlvmError::CouldNotCreate("module pass manager"))
+		}
+		else
+		{
+			Ok
+			(
+				Self
+				{
+					reference: reference,
+				}
+			)
+		}
+	}
+	
+	/// Function passes can also be added; they are run over every function in the module
+	#[inline(always)]
+	pub fn addPass(&self, namedPass: NamedPass)
+	{
+		namedPass.addTo(self.reference)
+	}
+	
+	/// Returns true if the module was modified
+	#[inline(always)]
+	pub fn run(&self, module: &Module) -> bool
+	{
+		unsafe { LLVMRunPassManager(self.reference, module.reference) != 0 }
+	}
+}
@@ -0,0 +1,142 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// A pass that can be added explicitly to a `ModulePassManager` or `FunctionPassManager`; named as in LLVM's C API (eg `InstructionCombining` is `LLVMAddInstructionCombiningPass`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NamedPass
+{
+	AggressiveDCE,
+	BitTrackingDCE,
+	AlignmentFromAssumptions,
+	CFGSimplification,
+	DeadStoreElimination,
+	Scalarizer,
+	MergedLoadStoreMotion,
+	GVN,
+	NewGVN,
+	IndVarSimplify,
+	InstructionCombining,
+	JumpThreading,
+	LICM,
+	LoopDeletion,
+	LoopIdiom,
+	LoopRotate,
+	LoopReroll,
+	LoopUnroll,
+	LoopUnswitch,
+	MemCpyOpt,
+	PartiallyInlineLibCalls,
+	LowerSwitch,
+	PromoteMemoryToRegister,
+	Reassociate,
+	SCCP,
+	ScalarReplAggregates,
+	ScalarReplAggregatesSSA,
+	ScalarReplAggregatesWithThreshold(i32),
+	SimplifyLibCalls,
+	TailCallElimination,
+	ConstantPropagation,
+	DemoteMemoryToRegister,
+	Verifier,
+	CorrelatedValuePropagation,
+	EarlyCSE,
+	EarlyCSEMemSSA,
+	LowerExpectIntrinsic,
+	TypeBasedAliasAnalysis,
+	ScopedNoAliasAA,
+	BasicAliasAnalysis,
+	
+	// Interprocedural
+	ArgumentPromotion,
+	ConstantMerge,
+	DeadArgElimination,
+	FunctionAttrs,
+	FunctionInlining,
+	AlwaysInliner,
+	GlobalDCE,
+	GlobalOptimizer,
+	IPConstantPropagation,
+	PruneEH,
+	IPSCCP,
+	
+	/// Internalizes all symbols apart from `main` (if `allButMain`)
+	Internalize { allButMain: bool },
+	StripDeadPrototypes,
+	StripSymbols,
+	
+	// Vectorisation
+	BBVectorize,
+	LoopVectorize,
+	SLPVectorize,
+}
+
+impl NamedPass
+{
+	#[inline(always)]
+	pub(crate) fn addTo(&self, passManagerReference: LLVMPassManagerRef)
+	{
+		use self::NamedPass::*;
+		
+		match *self
+		{
+			AggressiveDCE => unsafe { LLVMAddAggressiveDCEPass(passManagerReference) },
+			BitTrackingDCE => unsafe { LLVMAddBitTrackingDCEPass(passManagerReference) },
+			AlignmentFromAssumptions => unsafe { LLVMAddAlignmentFromAssumptionsPass(passManagerReference) },
+			CFGSimplification => unsafe { LLVMAddCFGSimplificationPass(passManagerReference) },
+			DeadStoreElimination => unsafe { LLVMAddDeadStoreEliminationPass(passManagerReference) },
+			Scalarizer => unsafe { LLVMAddScalarizerPass(passManagerReference) },
+			MergedLoadStoreMotion => unsafe { LLVMAddMergedLoadStoreMotionPass(passManagerReference) },
+			GVN => unsafe { LLVMAddGVNPass(passManagerReference) },
+			NewGVN => unsafe { LLVMAddNewGVNPass(passManagerReference) },
+			IndVarSimplify => unsafe { LLVMAddIndVarSimplifyPass(passManagerReference) },
+			InstructionCombining => unsafe { LLVMAddInstructionCombiningPass(passManagerReference) },
+			JumpThreading => unsafe { LLVMAddJumpThreadingPass(passManagerReference) },
+			LICM => unsafe { LLVMAddLICMPass(passManagerReference) },
+			LoopDeletion => unsafe { LLVMAddLoopDeletionPass(passManagerReference) },
+			LoopIdiom => unsafe { LLVMAddLoopIdiomPass(passManagerReference) },
+			LoopRotate => unsafe { LLVMAddLoopRotatePass(passManagerReference) },
+			LoopReroll => unsafe { LLVMAddLoopRerollPass(passManagerReference) },
+			LoopUnroll => unsafe { LLVMAddLoopUnrollPass(passManagerReference) },
+			LoopUnswitch => unsafe { LLVMAddLoopUnswitchPass(passManagerReference) },
+			MemCpyOpt => unsafe { LLVMAddMemCpyOptPass(passManagerReference) },
+			PartiallyInlineLibCalls => unsafe { LLVMAddPartiallyInlineLibCallsPass(passManagerReference) },
+			LowerSwitch => unsafe { LLVMAddLowerSwitchPass(passManagerReference) },
+			PromoteMemoryToRegister => unsafe { LLVMAddPromoteMemoryToRegisterPass(passManagerReference) },
+			Reassociate => unsafe { LLVMAddReassociatePass(passManagerReference) },
+			SCCP => unsafe { LLVMAddSCCPPass(passManagerReference) },
+			ScalarReplAggregates => unsafe { LLVMAddScalarReplAggregatesPass(passManagerReference) },
+			ScalarReplAggregatesSSA => unsafe { LLVMAddScalarReplAggregatesSSAPass(passManagerReference) },
+			ScalarReplAggregatesWithThreshold(threshold) => unsafe { LLVMAddScalarReplAggregatesPassWithThreshold(passManagerReference, threshold) },
+			SimplifyLibCalls => unsafe { LLVMAddSimplifyLibCallsPass(passManagerReference) },
+			TailCallElimination => unsafe { LLVMAddTailCallEliminationPass(passManagerReference) },
+			ConstantPropagation => unsafe { LLVMAddConstantPropagationPass(passManagerReference) },
+			DemoteMemoryToRegister => unsafe { LLVMAddDemoteMemoryToRegisterPass(passManagerReference) },
+			Verifier => unsafe { LLVMAddVerifierPass(passManagerReference) },
+			CorrelatedValuePropagation => unsafe { LLVMAddCorrelatedValuePropagationPass(passManagerReference) },
+			EarlyCSE => unsafe { LLVMAddEarlyCSEPass(passManagerReference) },
+			EarlyCSEMemSSA => unsafe { LLVMAddEarlyCSEMemSSAPass(passManagerReference) },
+			LowerExpectIntrinsic => unsafe { LLVMAddLowerExpectIntrinsicPass(passManagerReference) },
+			TypeBasedAliasAnalysis => unsafe { LLVMAddTypeBasedAliasAnalysisPass(passManagerReference) },
+			ScopedNoAliasAA => unsafe { LLVMAddScopedNoAliasAAPass(passManagerReference) },
+			BasicAliasAnalysis => unsafe { LLVMAddBasicAliasAnalysisPass(passManagerReference) },
+			ArgumentPromotion => unsafe { LLVMAddArgumentPromotionPass(passManagerReference) },
+			ConstantMerge => unsafe { LLVMAddConstantMergePass(passManagerReference) },
+			DeadArgElimination => unsafe { LLVMAddDeadArgEliminationPass(passManagerReference) },
+			FunctionAttrs => unsafe { LLVMAddFunctionAttrsPass(passManagerReference) },
+			FunctionInlining => unsafe { LLVMAddFunctionInliningPass(passManagerReference) },
+			AlwaysInliner => unsafe { LLVMAddAlwaysInlinerPass(passManagerReference) },
+			GlobalDCE => unsafe { LLVMAddGlobalDCEPass(passManagerReference) },
+			GlobalOptimizer => unsafe { LLVMAddGlobalOptimizerPass(passManagerReference) },
+			IPConstantPropagation => unsafe { LLVMAddIPConstantPropagationPass(passManagerReference) },
+			PruneEH => unsafe { LLVMAddPruneEHPass(passManagerReference) },
+			IPSCCP => unsafe { LLVMAddIPSCCPPass(passManagerReference) },
+			Internalize { allButMain } => unsafe { LLVMAddInternalizePass(passManagerReference, if allButMain { 1 } else { 0 }) },
+			StripDeadPrototypes => unsafe { LLVMAddStripDeadPrototypesPass(passManagerReference) },
+			StripSymbols => unsafe { LLVMAddStripSymbolsPass(passManagerReference) },
+			BBVectorize => unsafe { LLVMAddBBVectorizePass(passManagerReference) },
+			LoopVectorize => unsafe { LLVMAddLoopVectorizePass(passManagerReference) },
+			SLPVectorize => unsafe { LLVMAddSLPVectorizePass(passManagerReference) },
+		}
+	}
+}
@@ -0,0 +1,102 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OptimisationLevel
+{
+	O0,
+	O1,
+	O2,
+	O3,
+	
+	/// O2, optimising for size
+	Os,
+	
+	/// O2, optimising aggressively for size
+	Oz,
+}
+
+impl Default for OptimisationLevel
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		OptimisationLevel::O2
+	}
+}
+
+impl OptimisationLevel
+{
+	#[inline(always)]
+	pub fn optimisationLevel(&self) -> c_uint
+	{
+		use self::OptimisationLevel::*;
+		
+		match *self
+		{
+			O0 => 0,
+			O1 => 1,
+			O2 | Os | Oz => 2,
+			O3 => 3,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn sizeLevel(&self) -> c_uint
+	{
+		use self::OptimisationLevel::*;
+		
+		match *self
+		{
+			Os => 1,
+			Oz => 2,
+			_ => 0,
+		}
+	}
+	
+	/// As clang; `None` means only functions marked `alwaysinline` are inlined
+	#[inline(always)]
+	pub fn defaultInlinerThreshold(&self) -> Option<u32>
+	{
+		use self::OptimisationLevel::*;
+		
+		match *self
+		{
+			O0 | O1 => None,
+			O2 => Some(225),
+			O3 => Some(250),
+			Os => Some(50),
+			Oz => Some(25),
+		}
+	}
+	
+	/// As clang, SLP vectorisation is on by default at O2 and O3
+	#[inline(always)]
+	pub fn vectorisesByDefault(&self) -> bool
+	{
+		use self::OptimisationLevel::*;
+		
+		match *self
+		{
+			O2 | O3 => true,
+			_ => false,
+		}
+	}
+	
+	/// The equivalent code generation optimisation level, eg for `JitContext::new()`
+	#[inline(always)]
+	pub fn codeGenerationOptimisationLevel(&self) -> LLVMCodeGenOptLevel
+	{
+		use self::OptimisationLevel::*;
+		use ::llvm_sys::target_machine::LLVMCodeGenOptLevel::*;
+		
+		match *self
+		{
+			O0 => LLVMCodeGenLevelNone,
+			O1 => LLVMCodeGenLevelLess,
+			O2 | Os | Oz => LLVMCodeGenLevelDefault,
+			O3 => LLVMCodeGenLevelAggressive,
+		}
+	}
+}
@@ -0,0 +1,122 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Configures LLVM's standard optimisation pipeline for an `OptimisationLevel`, followed by any explicitly named passes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PassManagerBuilder
+{
+	optimisationLevel: OptimisationLevel,
+	inlinerThreshold: Option<u32>,
+	superwordLevelParallelismVectorisation: bool,
+	unrollLoops: bool,
+	namedPasses: Vec<NamedPass>,
+}
+
+impl Default for PassManagerBuilder
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::new(OptimisationLevel::default())
+	}
+}
+
+impl PassManagerBuilder
+{
+	#[inline(always)]
+	pub fn new(optimisationLevel: OptimisationLevel) -> Self
+	{
+		Self
+		{
+			optimisationLevel: optimisationLevel,
+			inlinerThreshold: optimisationLevel.defaultInlinerThreshold(),
+			superwordLevelParallelismVectorisation: optimisationLevel.vectorisesByDefault(),
+			unrollLoops: optimisationLevel != OptimisationLevel::O0,
+			namedPasses: Vec::new(),
+		}
+	}
+	
+	/// `None` inlines only functions marked `alwaysinline`
+	#[inline(always)]
+	pub fn inlinerThreshold(mut self, inlinerThreshold: Option<u32>) -> Self
+	{
+		self.inlinerThreshold = inlinerThreshold;
+		self
+	}
+	
+	/// LLVM's C API can not turn this on in the standard pipeline, so, if enabled, `SLPVectorize` is added after it, followed by `InstructionCombining` and `CFGSimplification` to clean up, as the standard pipeline does
+	/// The C API can not turn on loop vectorisation either; the standard pipeline only vectorises loops that have vectorisation hints (metadata)
+	#[inline(always)]
+	pub fn superwordLevelParallelismVectorisation(mut self, enable: bool) -> Self
+	{
+		self.superwordLevelParallelismVectorisation = enable;
+		self
+	}
+	
+	#[inline(always)]
+	pub fn unrollLoops(mut self, enable: bool) -> Self
+	{
+		self.unrollLoops = enable;
+		self
+	}
+	
+	/// Added after the passes for the optimisation level, in the order given
+	#[inline(always)]
+	pub fn pass(mut self, namedPass: NamedPass) -> Self
+	{
+		self.namedPasses.push(namedPass);
+		self
+	}
+	
+	/// Runs the function passes over every function, then the module passes; returns true if the module was modified
+	pub fn optimise(&self, module: &Module) -> Result<bool, LlvmError>
+	{
+		let functionPassManager = FunctionPassManager::new(module)?;
+		let modulePassManager = ModulePassManager::new()?;
+		self.populate(&functionPassManager, &modulePassManager)?;
+		
+		let functionsModified = functionPassManager.run();
+		let moduleModified = modulePassManager.run(module);
+		Ok(functionsModified || moduleModified)
+	}
+	
+	pub fn populate(&self, functionPassManager: &FunctionPassManager, modulePassManager: &ModulePassManager) -> Result<(), LlvmError>
+	{
+		let reference = unsafe { LLVMPassManagerBuilderCreate() };
+		if unlikely(reference.is_null())
+		{
+			return Err(LlvmError::CouldNotCreate("pass manager builder"));
+		}
+		
+		unsafe { LLVMPassManagerBuilderSetOptLevel(reference, self.optimisationLevel.optimisationLevel()) };
+		unsafe { LLVMPassManagerBuilderSetSizeLevel(reference, self.optimisationLevel.sizeLevel()) };
+		unsafe { LLVMPassManagerBuilderSetDisableUnrollLoops(reference, if self.unrollLoops { 0 } else { 1 }) };
+		if let Some(inlinerThreshold) = self.inlinerThreshold
+		{
+			unsafe { LLVMPassManagerBuilderUseInlinerWithThreshold(reference, inlinerThreshold) };
+		}
+		else
+		{
+			modulePassManager.addPass(NamedPass::AlwaysInliner);
+		}
+		
+		unsafe { LLVMPassManagerBuilderPopulateFunctionPassManager(reference, functionPassManager.reference) };
+		unsafe { LLVMPassManagerBuilderPopulateModulePassManager(reference, modulePassManager.reference) };
+		unsafe { LLVMPassManagerBuilderDispose(reference) };
+		
+		if self.superwordLevelParallelismVectorisation
+		{
+			modulePassManager.addPass(NamedPass::SLPVectorize);
+			modulePassManager.addPass(NamedPass::InstructionCombining);
+			modulePassManager.addPass(NamedPass::CFGSimplification);
+		}
+		
+		for namedPass in self.namedPasses.iter()
+		{
+			modulePassManager.addPass(*namedPass);
+		}
+		
+		Ok(())
+	}
+}
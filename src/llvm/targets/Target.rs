@@ -11,19 +11,16 @@ pub struct Target
 
 impl Target
 {
-	pub fn createHostOrcJitStack(optimisationLevel: LLVMCodeGenOptLevel) -> Result<LLVMOrcJITStackRef, String>
+	/// See `TargetConfiguration` to generate code for a different CPU or with different features
+	pub fn createHostOrcJitStack(optimisationLevel: LLVMCodeGenOptLevel) -> Result<LLVMOrcJITStackRef, LlvmError>
 	{
-		let hostTargetMachine = Self::createHostTargetMachine(optimisationLevel)?;
-		hostTargetMachine.toOrcJitStack()
+		TargetConfiguration::host()?.optimisationLevel(optimisationLevel).createOrcJitStack()
 	}
 	
-	pub fn createHostTargetMachine(optimisationLevel: LLVMCodeGenOptLevel) -> Result<TargetMachine, String>
+	/// See `TargetConfiguration` to generate code for a different CPU or with different features
+	pub fn createHostTargetMachine(optimisationLevel: LLVMCodeGenOptLevel) -> Result<TargetMachine, LlvmError>
 	{
-		let hostTarget = Target::obtainTargetForHost()?;
-		let hostCpuName = ::llvmHostCpuName()?;
-		let hostCpuFeatures = ::llvmHostCpuFeatures()?;
-		
-		hostTarget.createTargetMachine(hostCpuName.as_ptr(), hostCpuFeatures.as_ptr(), optimisationLevel, LLVMRelocMode::LLVMRelocStatic, LLVMCodeModel::LLVMCodeModelJITDefault)
+		TargetConfiguration::host()?.optimisationLevel(optimisationLevel).createTargetMachine()
 	}
 	
 	#[inline(always)]
@@ -38,16 +35,77 @@ impl Target
 		value
 	}
 	
+	/// Only the native target is initialised by `SuperContext`; this initialises every other target LLVM was built with (and their assembly printers and parsers), so that code can be generated for them
+	/// Called by `fromTriple()` and `all()`; safe to call more than once and from any thread
+	pub fn initialiseAllTargets()
+	{
+		static InitialiseAllTargets: Once = ONCE_INIT;
+		
+		InitialiseAllTargets.call_once(||
+		{
+			unsafe { LLVM_InitializeAllTargetInfos() };
+			unsafe { LLVM_InitializeAllTargets() };
+			unsafe { LLVM_InitializeAllTargetMCs() };
+			unsafe { LLVM_InitializeAllAsmPrinters() };
+			unsafe { LLVM_InitializeAllAsmParsers() };
+		});
+	}
+	
+	/// Every target LLVM was built with; the triple of each is just its name (eg `aarch64`), so use `withTriple()` to choose a vendor, operating system and environment
+	pub fn all() -> Vec<Self>
+	{
+		Self::initialiseAllTargets();
+		
+		let mut targets = Vec::new();
+		let mut reference = unsafe { LLVMGetFirstTarget() };
+		while !reference.is_null()
+		{
+			let name = (unsafe { CStr::from_ptr(LLVMGetTargetName(reference)) }).to_owned();
+			targets.push
+			(
+				Self
+				{
+					reference: reference,
+					triple: name,
+				}
+			);
+			reference = unsafe { LLVMGetNextTarget(reference) };
+		}
+		targets
+	}
+	
+	/// eg `aarch64-unknown-linux-gnu`
 	#[inline(always)]
-	pub fn obtainTargetForHost() -> Result<Self, String>
+	pub fn fromTriple(targetTriple: &str) -> Result<Self, LlvmError>
 	{
-		let targetTriple = Self::defaultTargetTriple();
+		match CString::new(targetTriple)
+		{
+			Err(_) => Err(LlvmError::targetInitialisation(format!("Target triple '{}' contains embedded NULs", targetTriple.escape_default()))),
+			Ok(targetTriple) => Self::fromTripleCStringInitialisingAllTargets(targetTriple),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn fromTripleCStringInitialisingAllTargets(targetTriple: CString) -> Result<Self, LlvmError>
+	{
+		Self::initialiseAllTargets();
 		
+		Self::fromTripleCString(targetTriple)
+	}
+	
+	#[inline(always)]
+	pub fn obtainTargetForHost() -> Result<Self, LlvmError>
+	{
+		Self::fromTripleCString(Self::defaultTargetTriple())
+	}
+	
+	fn fromTripleCString(targetTriple: CString) -> Result<Self, LlvmError>
+	{
 		let mut targetReference = unsafe { uninitialized() };
 		
 		let mut errorMessage = null_mut();
 		let boolean = unsafe { LLVMGetTargetFromTriple(targetTriple.as_ptr(), &mut targetReference, &mut errorMessage) };
-		handle_boolean_and_error_message!(boolean, errorMessage, LLVMGetTargetFromTriple);
+		handle_boolean_and_error_message!(boolean, errorMessage, LLVMGetTargetFromTriple, |message| LlvmError::TargetInitialisation { message: message });
 		
 		Ok
 		(
@@ -59,6 +117,23 @@ impl Target
 		)
 	}
 	
+	/// The target's code generator is not checked against `targetTriple`
+	#[inline(always)]
+	pub fn withTriple(&self, targetTriple: CString) -> Self
+	{
+		Self
+		{
+			reference: self.reference,
+			triple: targetTriple,
+		}
+	}
+	
+	#[inline(always)]
+	pub fn triple<'a>(&'a self) -> &'a CStr
+	{
+		&self.triple
+	}
+	
 	#[inline(always)]
 	pub fn name<'a>(&'a self) -> &'a CStr
 	{
@@ -90,12 +165,12 @@ impl Target
 	}
 	
 	#[inline(always)]
-	pub fn createTargetMachine(&self, cpu: *const c_char, features: *const c_char, level: LLVMCodeGenOptLevel, relocationMode: LLVMRelocMode, codeModel: LLVMCodeModel) -> Result<TargetMachine, String>
+	pub fn createTargetMachine(&self, cpu: *const c_char, features: *const c_char, level: LLVMCodeGenOptLevel, relocationMode: LLVMRelocMode, codeModel: LLVMCodeModel) -> Result<TargetMachine, LlvmError>
 	{
 		let reference = unsafe { LLVMCreateTargetMachine(self.reference, self.triple.as_ptr(), cpu, features, level, relocationMode, codeModel) };
 		if reference.is_null()
 		{
-			Err("Could not create target machine".to_owned())
+			Err(LlvmError::targetInitialisation("Could not create target machine"))
 		}
 		else
 		{
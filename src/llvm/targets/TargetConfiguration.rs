@@ -0,0 +1,151 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+/// Chooses the target triple, CPU, CPU features, relocation model, code model and code generation optimisation level to generate code for
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetConfiguration
+{
+	targetTriple: CString,
+	cpuName: CString,
+	cpuFeatures: CString,
+	relocationMode: LLVMRelocMode,
+	codeModel: LLVMCodeModel,
+	optimisationLevel: LLVMCodeGenOptLevel,
+}
+
+impl TargetConfiguration
+{
+	/// The CPU this process is running on, with all of its features (eg AVX2 if available); code generated for it may not run on other CPUs
+	#[inline(always)]
+	pub fn host() -> Result<Self, LlvmError>
+	{
+		Ok(Self::new(::llvmHostCpuName()?, ::llvmHostCpuFeatures()?))
+	}
+	
+	/// The oldest CPU of the host's architecture, without optional features, so that generated code (eg cached object files) runs anywhere
+	#[inline(always)]
+	pub fn portable() -> Self
+	{
+		Self::new(CString::new("generic").unwrap(), CString::new("").unwrap())
+	}
+	
+	/// For the host's target triple; `cpuFeatures` is an LLVM feature string, eg `+avx2,-sse4a`
+	#[inline(always)]
+	pub fn new(cpuName: CString, cpuFeatures: CString) -> Self
+	{
+		Self
+		{
+			targetTriple: Target::defaultTargetTriple(),
+			cpuName: cpuName,
+			cpuFeatures: cpuFeatures,
+			relocationMode: LLVMRelocMode::LLVMRelocStatic,
+			codeModel: LLVMCodeModel::LLVMCodeModelJITDefault,
+			optimisationLevel: LLVMCodeGenOptLevel::LLVMCodeGenLevelAggressive,
+		}
+	}
+	
+	/// Cross-compiles, eg to compile plugins into object files on a build server for another machine (such as `aarch64-unknown-linux-gnu`); code generated for another triple can not be loaded into a JIT context
+	/// Defaults to the host's target triple
+	#[inline(always)]
+	pub fn forTargetTriple(mut self, targetTriple: CString) -> Self
+	{
+		self.targetTriple = targetTriple;
+		self
+	}
+	
+	#[inline(always)]
+	pub fn relocationMode(mut self, relocationMode: LLVMRelocMode) -> Self
+	{
+		self.relocationMode = relocationMode;
+		self
+	}
+	
+	#[inline(always)]
+	pub fn codeModel(mut self, codeModel: LLVMCodeModel) -> Self
+	{
+		self.codeModel = codeModel;
+		self
+	}
+	
+	#[inline(always)]
+	pub fn optimisationLevel(mut self, optimisationLevel: LLVMCodeGenOptLevel) -> Self
+	{
+		self.optimisationLevel = optimisationLevel;
+		self
+	}
+	
+	#[inline(always)]
+	pub fn targetTriple(&self) -> &CStr
+	{
+		&self.targetTriple
+	}
+	
+	#[inline(always)]
+	pub fn cpuName(&self) -> &CStr
+	{
+		&self.cpuName
+	}
+	
+	#[inline(always)]
+	pub fn cpuFeatures(&self) -> &CStr
+	{
+		&self.cpuFeatures
+	}
+	
+	#[inline(always)]
+	pub fn createTargetMachine(&self) -> Result<TargetMachine, LlvmError>
+	{
+		let target = if self.targetTriple == Target::defaultTargetTriple()
+		{
+			Target::obtainTargetForHost()?
+		}
+		else
+		{
+			Target::fromTripleCStringInitialisingAllTargets(self.targetTriple.clone())?
+		};
+		target.createTargetMachine(self.cpuName.as_ptr(), self.cpuFeatures.as_ptr(), self.optimisationLevel, self.relocationMode, self.codeModel)
+	}
+	
+	/// Fails for any target triple other than the host's, as the ORC JIT stack runs the code it generates in this process
+	#[inline(always)]
+	pub fn createOrcJitStack(&self) -> Result<LLVMOrcJITStackRef, LlvmError>
+	{
+		let hostTargetTriple = Target::defaultTargetTriple();
+		if unlikely(self.targetTriple != hostTargetTriple)
+		{
+			return Err(LlvmError::targetInitialisation(format!("Can not create an ORC JIT stack for target triple '{}' as it is not the host's, '{}'", self.targetTriple.to_string_lossy(), hostTargetTriple.to_string_lossy())));
+		}
+		
+		self.createTargetMachine()?.toOrcJitStack()
+	}
+	
+	/// Functions without `target-cpu` and `target-features` attributes are compiled for the target machine's CPU and features, so these are only needed to override them for particular functions (eg with `FunctionDefinition::private()`)
+	#[inline(always)]
+	pub fn targetDependentFunctionAttributes(&self) -> HashSet<TargetDependentFunctionAttribute>
+	{
+		hashset!
+		{
+			TargetDependentFunctionAttribute::target_cpu_owned(self.cpuName.to_bytes().to_vec()),
+			TargetDependentFunctionAttribute::target_features_owned(self.cpuFeatures.to_bytes().to_vec()),
+		}
+	}
+}
+
+impl Eq for TargetConfiguration
+{
+}
+
+impl Hash for TargetConfiguration
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, hasher: &mut H)
+	{
+		self.targetTriple.hash(hasher);
+		self.cpuName.hash(hasher);
+		self.cpuFeatures.hash(hasher);
+		(self.relocationMode as u32).hash(hasher);
+		(self.codeModel as u32).hash(hasher);
+		(self.optimisationLevel as u32).hash(hasher);
+	}
+}
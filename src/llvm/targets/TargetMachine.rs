@@ -31,14 +31,56 @@ impl TargetMachine
 		}
 	}
 	
+	/// `codeGenerationFileType` is either an object file (which can be loaded with `ObjectFile::create()` and `JitContext::loadPluginFromObjectFile()`) or assembly text
+	/// Code generation modifies `module`; use a copy (eg from `useAsTemplateForNewModule()`) if it is also to be loaded into a JIT
 	#[inline(always)]
-	pub fn toOrcJitStack(mut self) -> Result<LLVMOrcJITStackRef, String>
+	pub fn emitToMemoryBuffer(&self, module: &Module, codeGenerationFileType: LLVMCodeGenFileType) -> Result<MemoryBuffer<'static>, LlvmError>
+	{
+		let mut reference = unsafe { uninitialized() };
+		
+		let mut errorMessage = null_mut();
+		let boolean = unsafe { LLVMTargetMachineEmitToMemoryBuffer(self.reference, module.reference, codeGenerationFileType, &mut errorMessage, &mut reference) };
+		handle_boolean_and_error_message!(boolean, errorMessage, LLVMTargetMachineEmitToMemoryBuffer);
+		
+		Ok(MemoryBuffer::fromReference(reference))
+	}
+	
+	/// See `emitToMemoryBuffer()`
+	#[inline(always)]
+	pub fn emitToFile<P: AsRef<Path>>(&self, module: &Module, codeGenerationFileType: LLVMCodeGenFileType, filePath: P) -> Result<(), LlvmError>
+	{
+		let filePath = filePath.as_ref();
+		
+		let filePathCString = match CString::new(filePath.as_os_str().as_bytes())
+		{
+			Err(_) => return Err(LlvmError::FileIo { filePath: filePath.to_string_lossy().into_owned(), message: "File path contains embedded NULs".to_owned() }),
+			Ok(filePathCString) => filePathCString,
+		};
+		
+		let mut errorMessage = null_mut();
+		let boolean = unsafe { LLVMTargetMachineEmitToFile(self.reference, module.reference, filePathCString.as_ptr() as *mut _, codeGenerationFileType, &mut errorMessage) };
+		handle_boolean_and_error_message!(boolean, errorMessage, LLVMTargetMachineEmitToFile);
+		
+		Ok(())
+	}
+	
+	/// Convenience method for debugging, eg to inspect the code generated for a slow plugin
+	#[inline(always)]
+	pub fn emitAssembly(&self, module: &Module) -> Result<String, LlvmError>
+	{
+		let memoryBuffer = self.emitToMemoryBuffer(module, LLVMCodeGenFileType::LLVMAssemblyFile)?;
+		let assembly = unsafe { from_raw_parts(memoryBuffer.address() as *const u8, memoryBuffer.size()) };
+		Ok(String::from_utf8_lossy(assembly).into_owned())
+	}
+	
+	#[inline(always)]
+	pub fn toOrcJitStack(mut self) -> Result<LLVMOrcJITStackRef, LlvmError>
 	{
 		let orcJitStackReference = unsafe { LLVMOrcCreateInstance(self.reference) };
 		
 		if orcJitStackReference.is_null()
 		{
-			Err("Could not create ORC JIT stack".to_owned())
+			Err(LlvmError::targetInitialisation("Could not create ORC JIT stack"))
 		}
 		else
 		{
@@ -3,8 +3,10 @@
 
 
 use super::*;
+use ::std::collections::HashSet;
 
 
 include!("Target.rs");
+include!("TargetConfiguration.rs");
 include!("TargetMachine.rs");
 include!("TargetMachineDataLayout.rs");
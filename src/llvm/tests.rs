@@ -0,0 +1,98 @@
+// This file is part of predicator. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of predicator. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/predicator/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn symbolPatternMatches(pattern: &str, symbolName: &str) -> bool
+{
+	SymbolPattern::from(pattern).matches(&CString::new(symbolName).unwrap())
+}
+
+#[test]
+fn symbolPatternWithoutWildcardsMatchesOnlyTheSameName()
+{
+	assert!(symbolPatternMatches("malloc", "malloc"));
+	assert!(!symbolPatternMatches("malloc", "mallocx"));
+	assert!(!symbolPatternMatches("malloc", "mallo"));
+	assert!(!symbolPatternMatches("malloc", ""));
+	assert!(symbolPatternMatches("", ""));
+}
+
+#[test]
+fn symbolPatternStarMatchesAnyRunOfCharacters()
+{
+	assert!(symbolPatternMatches("llvm.memcpy.*", "llvm.memcpy.p0i8.p0i8.i64"));
+	assert!(symbolPatternMatches("llvm.memcpy.*", "llvm.memcpy."));
+	assert!(!symbolPatternMatches("llvm.memcpy.*", "llvm.memmove.p0i8.p0i8.i64"));
+	assert!(symbolPatternMatches("*", ""));
+	assert!(symbolPatternMatches("*", "anything"));
+	assert!(symbolPatternMatches("*_r", "strtok_r"));
+	assert!(symbolPatternMatches("a*b*c", "aXbYbZc"));
+	assert!(!symbolPatternMatches("a*b*c", "aXbYbZ"));
+	assert!(symbolPatternMatches("a**b", "ab"));
+}
+
+#[test]
+fn symbolPatternStarBacktracksPastPartialMatches()
+{
+	assert!(symbolPatternMatches("*abc", "ababc"));
+	assert!(symbolPatternMatches("*aab", "aaab"));
+	assert!(!symbolPatternMatches("*abc", "ababd"));
+}
+
+#[test]
+fn symbolPatternQuestionMarkMatchesExactlyOneCharacter()
+{
+	assert!(symbolPatternMatches("str?", "strx"));
+	assert!(!symbolPatternMatches("str?", "str"));
+	assert!(!symbolPatternMatches("str?", "strxy"));
+	assert!(symbolPatternMatches("?*", "x"));
+	assert!(!symbolPatternMatches("?*", ""));
+}
+
+#[test]
+fn llvmTypeDisplaysScalarsAsLlvmDoes()
+{
+	assert_eq!(LlvmType::Int1.to_string(), "i1");
+	assert_eq!(LlvmType::Int128.to_string(), "i128");
+	assert_eq!(LlvmType::Float16.to_string(), "half");
+	assert_eq!(LlvmType::Float80ForX86.to_string(), "x86_fp80");
+	assert_eq!(LlvmType::Float128ForPowerPCLegacy.to_string(), "ppc_fp128");
+	assert_eq!(LlvmType::Void.to_string(), "void");
+	assert_eq!(LlvmType::MmxX86.to_string(), "x86_mmx");
+}
+
+#[test]
+fn llvmTypeDisplaysDerivedTypesAsLlvmDoes()
+{
+	assert_eq!(LlvmType::int8Pointer().to_string(), "i8*");
+	assert_eq!(LlvmType::Pointer { elementType: Box::new(LlvmType::Int32), addressSpace: 3 }.to_string(), "i32 addrspace(3)*");
+	assert_eq!(LlvmType::array(LlvmType::pointer(LlvmType::Float64), 4).to_string(), "[4 x double*]");
+	assert_eq!(LlvmType::vector(LlvmType::Float32, 8).to_string(), "<8 x float>");
+}
+
+#[test]
+fn llvmTypeDisplaysStructsAsLlvmDoes()
+{
+	assert_eq!(LlvmType::anonymousStruct(false, vec![]).to_string(), "{}");
+	assert_eq!(LlvmType::anonymousStruct(false, vec![LlvmType::Int8, LlvmType::Int64]).to_string(), "{ i8, i64 }");
+	assert_eq!(LlvmType::anonymousStruct(true, vec![LlvmType::Int8, LlvmType::Int64]).to_string(), "<{ i8, i64 }>");
+	assert_eq!(LlvmType::namedStruct("struct.Node", false, vec![LlvmType::Int8]).to_string(), "%struct.Node");
+	assert_eq!(LlvmType::namedStruct("0abc", false, vec![]).to_string(), "%\"0abc\"");
+	assert_eq!(LlvmType::namedStruct("a b\"c\\", false, vec![]).to_string(), "%\"a b\\22c\\5C\"");
+	assert_eq!(LlvmType::namedStruct("tab\there", false, vec![]).to_string(), "%\"tab\\09here\"");
+}
+
+#[test]
+fn llvmTypeDisplaysFunctionsAsLlvmDoes()
+{
+	let function = |parameters: Vec<LlvmType>, hasVarArgs: bool| LlvmType::Function { returns: Box::new(LlvmType::Int32), parameters: parameters, hasVarArgs: hasVarArgs };
+	
+	assert_eq!(function(vec![], false).to_string(), "i32 ()");
+	assert_eq!(function(vec![LlvmType::int8Pointer(), LlvmType::Int64], false).to_string(), "i32 (i8*, i64)");
+	assert_eq!(function(vec![], true).to_string(), "i32 (...)");
+	assert_eq!(function(vec![LlvmType::int8Pointer()], true).to_string(), "i32 (i8*, ...)");
+	assert_eq!(LlvmType::pointer(function(vec![LlvmType::Int32], false)).to_string(), "i32 (i32)*");
+}
@@ -11,24 +11,28 @@ use ::predicator::llvm::*;
 
 fn main()
 {
-	// Compile plugin
-	let mut rust_plugin_compiler = RustPluginCompiler::new(TemporaryFolderPath::TempDir);
-	let plugin_bit_code_file_path = rust_plugin_compiler.example().expect("Did not compile plugin");
-	
 	// Create a super context
 	let super_context = SuperContext::threadLocal();
 	
 	// There needs to be at least one context per thread
-	let (jit_context, context) = super_context.newJitContext(NaiveSymbolResolver(0)).expect("Could not create a new JIT context");
+	// Code is generated for this machine's CPU and all of its features
+	let target_configuration = TargetConfiguration::host().expect("Could not obtain host CPU");
+	let (jit_context, context) = super_context.newJitContext(NaiveSymbolResolver(0), &target_configuration).expect("Could not create a new JIT context");
+	
+	// Compile plugin, embedding a manifest of its exports
+	// The compiled bit code file is removed when it is dropped
+	let mut rust_plugin_compiler = RustPluginCompiler::new(TemporaryFolderPath::TempDir).expect("Could not create temporary folder");
+	let plugin_bit_code_file = rust_plugin_compiler.example(&context).expect("Did not compile plugin").output;
 	
 	// Can also be created from a slice, and from intermediate representation (.ll files)
-	let plugins = jit_context.loadPlugins(ModuleSourceCodeType::BitCode, &MemoryBufferCreator::File(&plugin_bit_code_file_path), &context).expect("Could not load plugin");
+	// The plugin's manifest is checked when it is loaded
+	let plugins = jit_context.loadPlugins(ModuleSourceCodeType::BitCode, &MemoryBufferCreator::File(plugin_bit_code_file.filePath()), &context, CompilationMode::Eager, None).expect("Could not load plugin");
 	
-	// Note that there is no way to know the correct arity or arguments for the function pointer
-	let sample_plugin_function_pointer = plugins.nullaryFunctionPointer::<*const i8>("sample_plugin").expect("Missing function for sample_plugin");
+	// The function pointer type is checked against the function's signature in the plugin
+	let sample_plugin_function_pointer = plugins.functionPointer::<unsafe extern "C" fn() -> *const i8>("sample_plugin").expect("Missing or mismatched function for sample_plugin");
 	
 	// Execute the function
-	let result = unsafe { sample_plugin_function_pointer() };
+	let result = unsafe { sample_plugin_function_pointer.call() };
 	
 	// Prove the plugin was run
 	assert!(::std::ffi::CString::new("Hello, world from sample_plugin!").unwrap() == unsafe { ::std::ffi::CStr::from_ptr(result) }.to_owned());